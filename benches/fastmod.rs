@@ -0,0 +1,35 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use periodic_array::PeriodicVec;
+
+const ACCESSES: usize = 4096;
+
+fn periodic_access(c: &mut Criterion) {
+    let mut group = c.benchmark_group("runtime_period_access");
+
+    for period in [1000usize, 1024, 4099] {
+        let data: Vec<u64> = (0..period as u64).collect();
+        let pv = PeriodicVec::new(data.clone());
+        // Strided indices that cover several periods.
+        let indices: Vec<usize> = (0..ACCESSES).map(|i| i * 7919).collect();
+
+        group.bench_with_input(BenchmarkId::new("remainder", period), &period, |b, _| {
+            let len = black_box(data.len());
+            // Unchecked like `PeriodicVec` indexing, so only the reduction is compared.
+            b.iter(|| {
+                indices
+                    .iter()
+                    .map(|&i| unsafe { *data.get_unchecked(black_box(i) % len) })
+                    .sum::<u64>()
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("fastmod", period), &period, |b, _| {
+            b.iter(|| indices.iter().map(|&i| pv[black_box(i)]).sum::<u64>())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, periodic_access);
+criterion_main!(benches);
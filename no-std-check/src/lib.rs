@@ -0,0 +1,37 @@
+//! Exercises the `core`-only surface of `periodic-array`.
+//!
+//! Building this crate on its own, with `cargo build -p no-std-check`, compiles
+//! `periodic-array` with default features disabled, so any accidental use of `std`
+//! or `alloc` fails the build. With `--features fft`, it checks that the `fft` module
+//! needs only `alloc`.
+
+#![no_std]
+
+use periodic_array::{p_arr, FastMod, PeriodicArray, PeriodicGrid2, PeriodicSlice};
+
+/// A periodic lookup table, as used in firmware.
+pub fn lookup(table: &PeriodicArray<u8, 4>, i: isize) -> u8 {
+    table[i]
+}
+
+pub fn sum_window(i: usize) -> u32 {
+    let pa = p_arr![1u32, 2, 3, 4, 5];
+    pa.window(i, 3).iter().sum::<u32>() + pa.cycle_from(i).nth(7).copied().unwrap_or(0)
+}
+
+pub fn grid_corner(grid: &PeriodicGrid2<i16, 2, 3>) -> i16 {
+    grid[(-1, -1)]
+}
+
+pub fn reduce(period: usize, x: usize) -> usize {
+    FastMod::new(period).reduce(x)
+}
+
+pub fn slice_tail(samples: &[u8]) -> Option<u8> {
+    PeriodicSlice::try_new(samples).ok().map(|ps| ps[-1])
+}
+
+#[cfg(feature = "fft")]
+pub fn dc_component(x: &PeriodicArray<f64, 6>) -> f64 {
+    periodic_array::fft::forward_real(x)[0].re
+}
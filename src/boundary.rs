@@ -0,0 +1,335 @@
+//! Boundary conditions for reading arrays outside their bounds.
+//!
+//! [`BoundedArray`] stores `N` elements like [`PeriodicArray`] but defers out-of-range reads
+//! to a [`Boundary`], so a solver can switch between boundary conditions by changing a type
+//! parameter.
+//!
+//! # Examples
+//!
+//! ```
+//! use periodic_array::boundary::{BoundedArray, Constant, Mirror, Reflect};
+//!
+//! let data = [1, 2, 3, 4];
+//! let reflect = BoundedArray::new(data, Reflect);
+//! let mirror = BoundedArray::new(data, Mirror);
+//! let padded = BoundedArray::new(data, Constant(0));
+//!
+//! assert_eq!((-2..6).map(|i| reflect.get(i)).collect::<Vec<_>>(), [3, 2, 1, 2, 3, 4, 3, 2]);
+//! assert_eq!((-2..6).map(|i| mirror.get(i)).collect::<Vec<_>>(), [2, 1, 1, 2, 3, 4, 4, 3]);
+//! assert_eq!((-2..6).map(|i| padded.get(i)).collect::<Vec<_>>(), [0, 0, 1, 2, 3, 4, 0, 0]);
+//! ```
+
+use core::ops::{Deref, DerefMut, Neg};
+
+use crate::checked::period_of;
+use crate::{PeriodicArray, PeriodicIndex};
+
+/// A rule for the value of an array at any index, including ones outside `0..N`.
+///
+/// Implement this to add boundary conditions beyond the ones in this module.
+pub trait Boundary<T> {
+    /// Returns the value of `data` at `index`.
+    fn value_at<const N: usize>(&self, data: &PeriodicArray<T, N>, index: isize) -> T;
+}
+
+/// Repeats the elements: `... 2 3 | 0 1 2 3 | 0 1 ...`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Periodic;
+
+/// Reflects about the first and last elements without repeating them:
+/// `... 2 1 | 0 1 2 3 | 2 1 ...`.
+///
+/// This is the usual discretisation of a Neumann boundary at the edge samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Reflect;
+
+/// Reflects about the outer edges of the first and last elements, repeating them:
+/// `... 1 0 | 0 1 2 3 | 3 2 ...`.
+///
+/// This is the usual discretisation of a Neumann boundary halfway between samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Mirror;
+
+/// Repeats the first and last elements: `... 0 0 | 0 1 2 3 | 3 3 ...`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Clamp;
+
+/// Reads a fixed value outside the array: `... c c | 0 1 2 3 | c c ...`.
+///
+/// This is a Dirichlet boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Constant<T>(pub T);
+
+/// Repeats the elements, negating every other period: `... -2 -3 | 0 1 2 3 | -0 -1 ...`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AntiPeriodic;
+
+/// Splits `index` into its offset within a period of length `m` and whether the period
+/// (`index` divided by `m`, rounded down) is odd.
+#[inline(always)]
+fn fold(index: isize, m: usize) -> (usize, bool) {
+    (index.wrap(m), period_of(index, m) & 1 == 1)
+}
+
+impl<T: Clone> Boundary<T> for Periodic {
+    #[inline(always)]
+    fn value_at<const N: usize>(&self, data: &PeriodicArray<T, N>, index: isize) -> T {
+        data[index].clone()
+    }
+}
+
+impl<T: Clone> Boundary<T> for Reflect {
+    #[inline(always)]
+    fn value_at<const N: usize>(&self, data: &PeriodicArray<T, N>, index: isize) -> T {
+        if N == 1 {
+            return data.inner[0].clone();
+        }
+        let (offset, odd) = fold(index, N - 1);
+        let i = if odd { N - 1 - offset } else { offset };
+        // SAFETY: `offset < N - 1`, so both choices are in `0..N`.
+        unsafe { data.inner.get_unchecked(i).clone() }
+    }
+}
+
+impl<T: Clone> Boundary<T> for Mirror {
+    #[inline(always)]
+    fn value_at<const N: usize>(&self, data: &PeriodicArray<T, N>, index: isize) -> T {
+        let (offset, odd) = fold(index, N);
+        let i = if odd { N - 1 - offset } else { offset };
+        // SAFETY: `offset < N`, so both choices are in `0..N`.
+        unsafe { data.inner.get_unchecked(i).clone() }
+    }
+}
+
+impl<T: Clone> Boundary<T> for Clamp {
+    #[inline(always)]
+    fn value_at<const N: usize>(&self, data: &PeriodicArray<T, N>, index: isize) -> T {
+        let i = if index < 0 {
+            0
+        } else {
+            (index as usize).min(N - 1)
+        };
+        // SAFETY: `i` is clamped to `0..N`.
+        unsafe { data.inner.get_unchecked(i).clone() }
+    }
+}
+
+impl<T: Clone> Boundary<T> for Constant<T> {
+    #[inline(always)]
+    fn value_at<const N: usize>(&self, data: &PeriodicArray<T, N>, index: isize) -> T {
+        data.get_strict(index).unwrap_or(&self.0).clone()
+    }
+}
+
+impl<T: Clone + Neg<Output = T>> Boundary<T> for AntiPeriodic {
+    #[inline(always)]
+    fn value_at<const N: usize>(&self, data: &PeriodicArray<T, N>, index: isize) -> T {
+        let (offset, odd) = fold(index, N);
+        // SAFETY: `offset < N`.
+        let value = unsafe { data.inner.get_unchecked(offset).clone() };
+        if odd {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+/// A fixed-size array whose out-of-range reads follow the boundary condition `B`.
+///
+/// The elements themselves are stored and accessed exactly as in a [`PeriodicArray`];
+/// [`get`](BoundedArray::get) returns an owned value since boundaries such as [`Constant`]
+/// and [`AntiPeriodic`] produce values that are not stored anywhere.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements held in the array.
+/// * `N` - The compile-time fixed size of the array.
+/// * `B` - The boundary condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundedArray<T, const N: usize, B> {
+    inner: PeriodicArray<T, N>,
+    boundary: B,
+}
+
+impl<T, const N: usize, B> BoundedArray<T, N, B> {
+    /// Wraps `inner`, reading outside it according to `boundary`.
+    #[inline(always)]
+    pub fn new(inner: [T; N], boundary: B) -> Self {
+        BoundedArray {
+            inner: PeriodicArray::new(inner),
+            boundary,
+        }
+    }
+
+    /// Returns the value at `index`, applying the boundary condition outside `0..N`.
+    #[inline(always)]
+    pub fn get(&self, index: isize) -> T
+    where
+        B: Boundary<T>,
+    {
+        self.boundary.value_at(&self.inner, index)
+    }
+
+    /// Returns the boundary condition.
+    #[inline(always)]
+    pub fn boundary(&self) -> &B {
+        &self.boundary
+    }
+
+    /// Returns the boundary condition mutably, e.g. to change a [`Constant`] value.
+    #[inline(always)]
+    pub fn boundary_mut(&mut self) -> &mut B {
+        &mut self.boundary
+    }
+
+    /// Returns the elements as a `PeriodicArray`, dropping the boundary condition.
+    #[inline(always)]
+    pub fn into_periodic(self) -> PeriodicArray<T, N> {
+        self.inner
+    }
+}
+
+impl<T, const N: usize, B> Deref for BoundedArray<T, N, B> {
+    type Target = [T; N];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.inner.inner
+    }
+}
+
+impl<T, const N: usize, B> DerefMut for BoundedArray<T, N, B> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner.inner
+    }
+}
+
+impl<T, const N: usize, B: Default> From<PeriodicArray<T, N>> for BoundedArray<T, N, B> {
+    #[inline(always)]
+    fn from(inner: PeriodicArray<T, N>) -> Self {
+        BoundedArray {
+            inner,
+            boundary: B::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        fold, AntiPeriodic, Boundary, BoundedArray, Clamp, Constant, Mirror, Periodic, Reflect,
+    };
+    use crate::p_arr;
+
+    fn read<const N: usize, B: Boundary<i32>>(
+        array: &BoundedArray<i32, N, B>,
+        range: core::ops::Range<isize>,
+    ) -> Vec<i32> {
+        range.map(|i| array.get(i)).collect()
+    }
+
+    #[test]
+    pub fn boundaries_extend_the_array() {
+        let data = [0, 1, 2, 3];
+        let range = -6..10;
+
+        let periodic = BoundedArray::new(data, Periodic);
+        assert_eq!(
+            read(&periodic, range.clone()),
+            [2, 3, 0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3, 0, 1]
+        );
+        let reflect = BoundedArray::new(data, Reflect);
+        assert_eq!(
+            read(&reflect, range.clone()),
+            [0, 1, 2, 3, 2, 1, 0, 1, 2, 3, 2, 1, 0, 1, 2, 3]
+        );
+        let mirror = BoundedArray::new(data, Mirror);
+        assert_eq!(
+            read(&mirror, range.clone()),
+            [2, 3, 3, 2, 1, 0, 0, 1, 2, 3, 3, 2, 1, 0, 0, 1]
+        );
+        let clamp = BoundedArray::new(data, Clamp);
+        assert_eq!(
+            read(&clamp, range.clone()),
+            [0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 3, 3, 3, 3, 3, 3]
+        );
+        let constant = BoundedArray::new(data, Constant(-1));
+        assert_eq!(
+            read(&constant, range.clone()),
+            [-1, -1, -1, -1, -1, -1, 0, 1, 2, 3, -1, -1, -1, -1, -1, -1]
+        );
+
+        let anti = BoundedArray::new([1, 2, 3, 4], AntiPeriodic);
+        assert_eq!(
+            read(&anti, range),
+            [3, 4, -1, -2, -3, -4, 1, 2, 3, 4, -1, -2, -3, -4, 1, 2]
+        );
+    }
+
+    #[test]
+    pub fn single_element_and_extreme_indices() {
+        assert_eq!(BoundedArray::new([7], Reflect).get(-5), 7);
+        assert_eq!(BoundedArray::new([7], Mirror).get(isize::MAX), 7);
+        assert_eq!(BoundedArray::new([7], AntiPeriodic).get(-1), -7);
+
+        let data = [1, 2, 3];
+        assert_eq!(BoundedArray::new(data, Clamp).get(isize::MIN), 1);
+        assert_eq!(BoundedArray::new(data, Reflect).get(isize::MIN), 1);
+        assert_eq!(BoundedArray::new(data, Mirror).get(isize::MAX), 2);
+    }
+
+    #[test]
+    pub fn zero_sized_elements_fill_every_index() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Z;
+
+        impl core::ops::Neg for Z {
+            type Output = Z;
+            fn neg(self) -> Z {
+                Z
+            }
+        }
+
+        const N: usize = usize::MAX;
+        assert_eq!(fold(0, N), (0, false));
+        assert_eq!(fold(isize::MAX, N), (isize::MAX as usize, false));
+        assert_eq!(fold(-1, N), (N - 1, true));
+        assert_eq!(
+            fold(isize::MIN, N - 1),
+            (N - 1 - isize::MIN.unsigned_abs(), true)
+        );
+
+        let indices = [0, 1, 2, isize::MAX, -1, isize::MIN];
+        assert!(indices
+            .iter()
+            .all(|&i| BoundedArray::new([Z; N], Periodic).get(i) == Z));
+        assert!(indices
+            .iter()
+            .all(|&i| BoundedArray::new([Z; N], Reflect).get(i) == Z));
+        assert!(indices
+            .iter()
+            .all(|&i| BoundedArray::new([Z; N], Mirror).get(i) == Z));
+        assert!(indices
+            .iter()
+            .all(|&i| BoundedArray::new([Z; N], Clamp).get(i) == Z));
+        assert!(indices
+            .iter()
+            .all(|&i| BoundedArray::new([Z; N], Constant(Z)).get(i) == Z));
+        assert!(indices
+            .iter()
+            .all(|&i| BoundedArray::new([Z; N], AntiPeriodic).get(i) == Z));
+    }
+
+    #[test]
+    pub fn elements_and_boundary_are_mutable() {
+        let mut padded: BoundedArray<_, 3, _> = BoundedArray::new([1.0, 2.0, 3.0], Constant(0.0));
+        padded[1] = 20.0;
+        padded.boundary_mut().0 = f64::NAN;
+        assert_eq!(padded.get(1), 20.0);
+        assert!(padded.get(3).is_nan());
+
+        let mirror: BoundedArray<_, 3, Mirror> = p_arr![1, 2, 3].into();
+        assert_eq!(*mirror.into_periodic(), [1, 2, 3]);
+    }
+}
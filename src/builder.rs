@@ -0,0 +1,54 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+/// Fills a `[T; N]` one element at a time, dropping whatever was pushed if abandoned early.
+pub(crate) struct ArrayBuilder<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayBuilder<T, N> {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        ArrayBuilder {
+            buf: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements pushed so far.
+    #[inline(always)]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Appends `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the builder already holds `N` elements.
+    #[inline]
+    pub(crate) fn push(&mut self, value: T) {
+        self.buf[self.len].write(value);
+        self.len += 1;
+    }
+
+    /// Returns the finished array, or `None` if fewer than `N` elements were pushed.
+    #[inline]
+    pub(crate) fn build(self) -> Option<[T; N]> {
+        if self.len < N {
+            return None;
+        }
+        let this = ManuallyDrop::new(self);
+        // SAFETY: all `N` elements are initialised, and `this` will not drop them again.
+        Some(unsafe { (this.buf.as_ptr() as *const [T; N]).read() })
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayBuilder<T, N> {
+    fn drop(&mut self) {
+        for slot in &mut self.buf[..self.len] {
+            // SAFETY: the first `len` elements are initialised.
+            unsafe { slot.assume_init_drop() };
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{PeriodicArray, PeriodicIndex};
+
+impl<T, const N: usize> PeriodicArray<T, N> {
+    /// Returns the element at `index` without wrapping, or `None` if `index` lies outside
+    /// `0..N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// let pa = p_arr![1, 2, 3];
+    /// assert_eq!(pa.get_strict(2), Some(&3));
+    /// assert_eq!(pa.get_strict(3), None);
+    /// assert_eq!(pa.get_strict(-1), None);
+    /// ```
+    #[inline]
+    pub fn get_strict<I: TryInto<usize>>(&self, index: I) -> Option<&T> {
+        self.inner.get(index.try_into().ok()?)
+    }
+
+    /// Returns the element at `index` mutably without wrapping, or `None` if `index` lies
+    /// outside `0..N`.
+    #[inline]
+    pub fn get_strict_mut<I: TryInto<usize>>(&mut self, index: I) -> Option<&mut T> {
+        self.inner.get_mut(index.try_into().ok()?)
+    }
+
+    /// Returns the element at `index`, which is expected to lie in the first period `0..N`.
+    ///
+    /// Debug builds panic if it does not, catching indices that should never wrap. Release
+    /// builds wrap `index` like regular indexing does, so access is always in bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// let pa = p_arr![1, 2, 3];
+    /// assert_eq!(*pa.get_in_period(1), 2);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn get_in_period<I: PeriodicIndex + TryInto<usize>>(&self, index: I) -> &T {
+        debug_assert!(
+            index.try_into().is_ok_and(|i| i < N),
+            "index is outside the first period of length {N}"
+        );
+        &self[index]
+    }
+
+    /// Returns the element at `index` mutably, which is expected to lie in the first period
+    /// `0..N`.
+    ///
+    /// Debug builds panic if it does not, while release builds wrap `index`.
+    #[inline]
+    #[track_caller]
+    pub fn get_in_period_mut<I: PeriodicIndex + TryInto<usize>>(&mut self, index: I) -> &mut T {
+        debug_assert!(
+            index.try_into().is_ok_and(|i| i < N),
+            "index is outside the first period of length {N}"
+        );
+        &mut self[index]
+    }
+
+    /// Returns the element at the wrapped `index`, together with the number of whole periods
+    /// between it and `index`.
+    ///
+    /// The period count is `index` divided by `N`, rounded down, so indices in `0..N` are in
+    /// period `0`, `N..2N` in period `1` and `-N..0` in period `-1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// let pa = p_arr![1, 2, 3];
+    /// assert_eq!(pa.get_wrapped_with_period(7), (&2, 2));
+    /// assert_eq!(pa.get_wrapped_with_period(-1), (&3, -1));
+    /// ```
+    #[inline]
+    pub fn get_wrapped_with_period(&self, index: isize) -> (&T, isize) {
+        (&self[index], period_of(index, N))
+    }
+
+    /// Returns the element at the wrapped `index` mutably, together with the number of whole
+    /// periods between it and `index`.
+    #[inline]
+    pub fn get_wrapped_with_period_mut(&mut self, index: isize) -> (&mut T, isize) {
+        (&mut self[index], period_of(index, N))
+    }
+}
+
+/// Returns `index` divided by `period`, rounded down.
+#[inline(always)]
+pub(crate) fn period_of(index: isize, period: usize) -> isize {
+    if period > isize::MAX as usize {
+        // Only arrays of zero-sized elements get this long; every `isize` is within one
+        // period of zero.
+        if index < 0 {
+            -1
+        } else {
+            0
+        }
+    } else {
+        index.div_euclid(period as isize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{p_arr, PeriodicArray};
+
+    #[test]
+    pub fn strict_access_does_not_wrap() {
+        let mut pa = p_arr![1, 2, 3];
+
+        assert_eq!(pa.get_strict(0u8), Some(&1));
+        assert_eq!(pa.get_strict(3usize), None);
+        assert_eq!(pa.get_strict(i64::MIN), None);
+        assert_eq!(pa.get_strict(u128::MAX), None);
+
+        *pa.get_strict_mut(1).unwrap() = 20;
+        assert_eq!(pa.get_strict_mut(-3), None);
+        assert_eq!(*pa, [1, 20, 3]);
+    }
+
+    #[test]
+    pub fn access_within_first_period() {
+        let mut pa = p_arr![1, 2, 3];
+
+        *pa.get_in_period_mut(2u8) += 1;
+        assert_eq!(*pa.get_in_period(2), 4);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "outside the first period")]
+    pub fn access_outside_first_period_panics_in_debug() {
+        let pa = p_arr![1, 2, 3];
+        pa.get_in_period(-1);
+    }
+
+    #[test]
+    pub fn period_counts() {
+        let mut pa = p_arr![1, 2, 3];
+
+        assert_eq!(pa.get_wrapped_with_period(0), (&1, 0));
+        assert_eq!(pa.get_wrapped_with_period(2), (&3, 0));
+        assert_eq!(pa.get_wrapped_with_period(3), (&1, 1));
+        assert_eq!(pa.get_wrapped_with_period(-3), (&1, -1));
+        assert_eq!(pa.get_wrapped_with_period(-4), (&3, -2));
+        assert_eq!(pa.get_wrapped_with_period(isize::MIN).1, isize::MIN / 3 - 1);
+
+        let (value, period) = pa.get_wrapped_with_period_mut(-2);
+        *value *= 10 * period;
+        assert_eq!(*pa, [1, -20, 3]);
+
+        let zsts = PeriodicArray::<(), { usize::MAX }>::new([(); usize::MAX]);
+        assert_eq!(zsts.get_wrapped_with_period(isize::MAX).1, 0);
+        assert_eq!(zsts.get_wrapped_with_period(-1).1, -1);
+    }
+}
@@ -0,0 +1,166 @@
+use core::iter::Sum;
+
+use crate::builder::ArrayBuilder;
+use crate::{LengthMismatchError, PeriodicArray};
+
+impl<T, const N: usize> PeriodicArray<T, N> {
+    /// Creates an array whose element at each index `i` is `f(i)`.
+    ///
+    /// See [`p_arr!`](crate::p_arr) for a form usable in `const` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::PeriodicArray;
+    ///
+    /// let pa = PeriodicArray::<usize, 4>::from_fn(|i| i * 10);
+    /// assert_eq!(pa[-1], 30);
+    /// ```
+    #[inline]
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        PeriodicArray::new(core::array::from_fn(f))
+    }
+
+    /// Creates an array with every element set to zero, as given by summing no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::PeriodicArray;
+    ///
+    /// assert_eq!(*PeriodicArray::<f32, 3>::zeroed(), [0.0; 3]);
+    /// ```
+    #[inline]
+    pub fn zeroed() -> Self
+    where
+        T: Sum,
+    {
+        PeriodicArray::from_fn(|_| core::iter::empty().sum())
+    }
+
+    /// Clones the elements of `slice`, which must hold exactly `N` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::PeriodicArray;
+    ///
+    /// let data = [1, 2, 3, 4];
+    /// assert!(PeriodicArray::<i32, 4>::try_from_slice(&data).is_ok());
+    /// assert!(PeriodicArray::<i32, 3>::try_from_slice(&data).is_err());
+    /// ```
+    #[inline]
+    pub fn try_from_slice(slice: &[T]) -> Result<Self, LengthMismatchError>
+    where
+        T: Clone,
+    {
+        match <&[T; N]>::try_from(slice) {
+            Ok(array) => Ok(PeriodicArray::new(array.clone())),
+            Err(_) => Err(LengthMismatchError {
+                expected: N,
+                found: slice.len(),
+            }),
+        }
+    }
+
+    /// Collects `iter`, which must yield exactly `N` elements.
+    ///
+    /// At most `N + 1` elements are consumed, so an endless iterator fails rather than
+    /// hanging.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::PeriodicArray;
+    ///
+    /// let pa = PeriodicArray::<u32, 3>::try_from_iter((1..=3).map(|x| x * x)).unwrap();
+    /// assert_eq!(*pa, [1, 4, 9]);
+    ///
+    /// let err = PeriodicArray::<u32, 3>::try_from_iter(0..).unwrap_err();
+    /// assert_eq!((err.expected, err.found), (3, 4));
+    /// ```
+    pub fn try_from_iter<It: IntoIterator<Item = T>>(
+        iter: It,
+    ) -> Result<Self, LengthMismatchError> {
+        let mut builder = ArrayBuilder::new();
+        for value in iter {
+            if builder.len() == N {
+                return Err(LengthMismatchError {
+                    expected: N,
+                    found: N + 1,
+                });
+            }
+            builder.push(value);
+        }
+        let found = builder.len();
+        builder
+            .build()
+            .map(PeriodicArray::new)
+            .ok_or(LengthMismatchError { expected: N, found })
+    }
+}
+
+impl<T: Default, const N: usize> Default for PeriodicArray<T, N> {
+    #[inline]
+    fn default() -> Self {
+        PeriodicArray::from_fn(|_| T::default())
+    }
+}
+
+impl<T: Clone, const N: usize> TryFrom<&[T]> for PeriodicArray<T, N> {
+    type Error = LengthMismatchError;
+
+    #[inline]
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        PeriodicArray::try_from_slice(slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LengthMismatchError, PeriodicArray};
+
+    #[test]
+    pub fn from_fn_default_and_zeroed() {
+        assert_eq!(*PeriodicArray::<_, 3>::from_fn(|i| i as i8 - 1), [-1, 0, 1]);
+        assert_eq!(*PeriodicArray::<String, 2>::default(), ["", ""]);
+        assert_eq!(*PeriodicArray::<u64, 2>::zeroed(), [0, 0]);
+    }
+
+    #[test]
+    pub fn slices_must_match_the_length() {
+        let data = vec![String::from("a"), String::from("b")];
+
+        let pa = PeriodicArray::<String, 2>::try_from(&data[..]).unwrap();
+        assert_eq!(pa[3], "b");
+        assert_eq!(
+            PeriodicArray::<String, 3>::try_from_slice(&data),
+            Err(LengthMismatchError {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    pub fn iterators_must_match_the_length() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let short = PeriodicArray::<Rc<()>, 4>::try_from_iter(vec![tracker.clone(); 3]);
+        assert_eq!(
+            short.unwrap_err(),
+            LengthMismatchError {
+                expected: 4,
+                found: 3
+            }
+        );
+        let long = PeriodicArray::<Rc<()>, 2>::try_from_iter(vec![tracker.clone(); 5]);
+        assert_eq!(long.unwrap_err().found, 3);
+        // partially collected elements are dropped on failure
+        assert_eq!(Rc::strong_count(&tracker), 1);
+
+        let err = PeriodicArray::<u8, 2>::try_from_iter(0..).unwrap_err();
+        assert_eq!(err.to_string(), "expected 2 elements, found more");
+    }
+}
@@ -0,0 +1,114 @@
+use core::ops::{Add, Mul};
+
+use crate::PeriodicArray;
+
+/// Returns the circular convolution of `a` and `b`, `out[i] = Σ a[k] * b[i - k]`.
+///
+/// Indices wrap around, so every output element sums over all `N` products. This is the
+/// direct O(N²) evaluation, which is the fastest choice for small `N` and exact for integer
+/// elements. For large `N` of `f64` or `Complex<f64>`, the `fft` feature adds
+/// `fft::convolve_real` and `fft::convolve`, which take O(N log N) and keep this direct path
+/// for small lengths.
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::{circular_convolve, p_arr};
+///
+/// let signal = p_arr![1, 2, 3, 4];
+/// let kernel = p_arr![1, 1, 0, 0];
+/// assert_eq!(*circular_convolve(&signal, &kernel), [5, 3, 5, 7]);
+/// ```
+#[inline]
+pub fn circular_convolve<T, const N: usize>(
+    a: &PeriodicArray<T, N>,
+    b: &PeriodicArray<T, N>,
+) -> PeriodicArray<T, N>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    PeriodicArray::new(core::array::from_fn(|i| {
+        // `i - k` wrapped into `0..N`
+        let term = |k: usize| {
+            let j = if i >= k { i - k } else { i + (N - k) };
+            unsafe { *a.inner.get_unchecked(k) * *b.inner.get_unchecked(j) }
+        };
+        (1..N).fold(term(0), |acc, k| acc + term(k))
+    }))
+}
+
+/// Returns the circular cross-correlation of `a` and `b`, `out[i] = Σ a[k] * b[k + i]`.
+///
+/// `out[i]` measures how well `b` matches `a` when shifted left by `i`. No conjugation is
+/// applied, so for complex signals conjugate `a` first. This is the direct O(N²) evaluation;
+/// with the `fft` feature, `fft::correlate_real` and `fft::correlate` are faster for large
+/// `N`.
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::{circular_correlate, p_arr};
+///
+/// let a = p_arr![0, 1, 0, 0];
+/// let b = p_arr![0, 0, 0, 1];
+/// // `b` lines up with `a` after a shift by 2
+/// assert_eq!(*circular_correlate(&a, &b), [0, 0, 1, 0]);
+/// ```
+#[inline]
+pub fn circular_correlate<T, const N: usize>(
+    a: &PeriodicArray<T, N>,
+    b: &PeriodicArray<T, N>,
+) -> PeriodicArray<T, N>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    PeriodicArray::new(core::array::from_fn(|i| {
+        // `k + i` wrapped into `0..N`
+        let term = |k: usize| {
+            let j = if k < N - i { k + i } else { k - (N - i) };
+            unsafe { *a.inner.get_unchecked(k) * *b.inner.get_unchecked(j) }
+        };
+        (1..N).fold(term(0), |acc, k| acc + term(k))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{circular_convolve, circular_correlate, p_arr, PeriodicArray};
+
+    /// Evaluates the definitions literally, with signed periodic indexing.
+    fn reference<const N: usize>(
+        a: &PeriodicArray<i64, N>,
+        b: &PeriodicArray<i64, N>,
+        sign: isize,
+    ) -> [i64; N] {
+        core::array::from_fn(|i| {
+            (0..N as isize)
+                .map(|k| a[k] * b[sign * k + i as isize])
+                .sum()
+        })
+    }
+
+    #[test]
+    pub fn agrees_with_reference() {
+        let a = PeriodicArray::<i64, 7>::new(core::array::from_fn(|i| (i * i) as i64 - 9));
+        let b = PeriodicArray::<i64, 7>::new(core::array::from_fn(|i| 3 - 2 * i as i64));
+
+        assert_eq!(*circular_convolve(&a, &b), reference(&a, &b, -1));
+        assert_eq!(*circular_correlate(&a, &b), reference(&a, &b, 1));
+        // convolution commutes, correlation reverses its lag
+        assert_eq!(circular_convolve(&a, &b), circular_convolve(&b, &a));
+        let (ab, ba) = (circular_correlate(&a, &b), circular_correlate(&b, &a));
+        assert!((0..7).all(|i| ab[i] == ba[-i]));
+    }
+
+    #[test]
+    pub fn delta_is_identity() {
+        let delta = p_arr![1.0, 0.0, 0.0, 0.0, 0.0];
+        let x = p_arr![0.5, -1.0, 2.0, 4.0, 8.0];
+
+        assert_eq!(circular_convolve(&x, &delta), x);
+        assert_eq!(circular_correlate(&delta, &x), x);
+        assert_eq!(*circular_convolve(&p_arr![3], &p_arr![4]), [12]);
+    }
+}
@@ -0,0 +1,46 @@
+use core::fmt;
+
+/// The error returned when a runtime-length periodic container would be empty.
+///
+/// Wrapping an index by a period of zero is undefined, so `PeriodicVec`
+/// and [`PeriodicSlice`](crate::PeriodicSlice) refuse to wrap empty collections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroLengthError;
+
+impl fmt::Display for ZeroLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("periodic containers must have a non-zero length")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ZeroLengthError {}
+
+/// The error returned when data of the wrong length is wrapped in a fixed-length container.
+///
+/// Returned by [`PeriodicArray::try_from_slice`](crate::PeriodicArray::try_from_slice) and
+/// [`PeriodicArray::try_from_iter`](crate::PeriodicArray::try_from_iter).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthMismatchError {
+    /// The length of the container.
+    pub expected: usize,
+    /// The length of the data. Iterators stop being counted one element past `expected`.
+    pub found: usize,
+}
+
+impl fmt::Display for LengthMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.found > self.expected {
+            write!(f, "expected {} elements, found more", self.expected)
+        } else {
+            write!(
+                f,
+                "expected {} elements, found {}",
+                self.expected, self.found
+            )
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthMismatchError {}
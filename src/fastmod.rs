@@ -0,0 +1,126 @@
+/// A period known only at runtime, precomputed so that reducing an index by it needs no division.
+///
+/// With a const generic length, `index % N` is strength-reduced by the compiler. Runtime
+/// periods do not get that treatment, so `FastMod` does it by hand: power-of-two periods
+/// reduce with a bit mask, and every other period uses Lemire's direct remainder
+/// computation with a 128-bit precomputed reciprocal, which is exact for every `usize` index.
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::FastMod;
+///
+/// let m = FastMod::new(7);
+/// assert_eq!(m.reduce(23), 23 % 7);
+/// assert_eq!(m.reduce(usize::MAX), usize::MAX % 7);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FastMod {
+    period: usize,
+    kind: Kind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Kind {
+    /// `period - 1` for power-of-two periods.
+    Mask(usize),
+    /// `ceil(2^128 / period)` for every other period.
+    Reciprocal(u128),
+}
+
+impl FastMod {
+    /// Precomputes the reduction for `period`.
+    ///
+    /// This is a `const fn`, so the reduction for a period known at compile time can be
+    /// computed once in a `const` rather than on every call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    #[inline]
+    pub const fn new(period: usize) -> Self {
+        assert!(period > 0, "FastMod period must be non-zero");
+        let kind = if period.is_power_of_two() {
+            Kind::Mask(period - 1)
+        } else {
+            // `period` is at least 3 here, so this cannot overflow.
+            Kind::Reciprocal(u128::MAX / period as u128 + 1)
+        };
+        FastMod { period, kind }
+    }
+
+    /// Returns the period this reduction was computed for.
+    #[inline(always)]
+    pub const fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Returns `x % self.period()`.
+    #[inline(always)]
+    pub fn reduce(&self, x: usize) -> usize {
+        match self.kind {
+            Kind::Mask(mask) => x & mask,
+            Kind::Reciprocal(c) => {
+                let fraction = c.wrapping_mul(x as u128);
+                mul_high(fraction, self.period as u64) as usize
+            }
+        }
+    }
+
+    /// Returns `x % self.period()` for indices that may not fit in a `usize`.
+    ///
+    /// Values above `usize::MAX` fall back to a 128-bit division.
+    #[inline(always)]
+    pub(crate) fn reduce_wide(&self, x: u128) -> usize {
+        if x <= usize::MAX as u128 {
+            self.reduce(x as usize)
+        } else {
+            (x % self.period as u128) as usize
+        }
+    }
+}
+
+/// Returns the upper 128 bits of the 192-bit product `a * b`.
+#[inline(always)]
+fn mul_high(a: u128, b: u64) -> u64 {
+    let b = b as u128;
+    let low = (a as u64 as u128) * b;
+    let high = (a >> 64) * b;
+    // `high` is at most (2^64 - 1)^2, so adding the carry from `low` cannot overflow.
+    ((high + (low >> 64)) >> 64) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FastMod;
+
+    #[test]
+    pub fn matches_remainder() {
+        let periods = [1, 2, 3, 7, 10, 64, 1000, 4097, usize::MAX / 3, usize::MAX];
+        let values = [0, 1, 2, 63, 64, 999, 1 << 40, usize::MAX - 1, usize::MAX];
+
+        for &period in &periods {
+            let m = FastMod::new(period);
+            for &x in &values {
+                assert_eq!(m.reduce(x), x % period, "{x} % {period}");
+            }
+            assert_eq!(
+                m.reduce_wide(u128::MAX),
+                (u128::MAX % period as u128) as usize
+            );
+        }
+    }
+
+    #[test]
+    pub fn const_construction() {
+        const SEVEN: FastMod = FastMod::new(7);
+        const { assert!(SEVEN.period() == 7) };
+        assert_eq!(SEVEN.reduce(23), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn zero_period_panics() {
+        FastMod::new(0);
+    }
+}
@@ -0,0 +1,357 @@
+//! Discrete Fourier transforms of periodic arrays.
+//!
+//! Requires the `fft` feature, which needs `alloc` but not `std`. The forward transform is
+//! `X[k] = Σ x[n] e^(-2πi kn / N)` and the inverse divides by `N`, so
+//! [`inverse`]`(`[`forward`]`(x))` returns `x` up to rounding.
+//!
+//! Power-of-two lengths use an iterative radix-2 transform. Every other length, including
+//! primes, goes through Bluestein's algorithm, which re-expresses the transform as a
+//! convolution of power-of-two length. Both run in O(N log N); Bluestein allocates scratch
+//! buffers of about four times `N`.
+//!
+//! # Examples
+//!
+//! ```
+//! use periodic_array::fft::{self, Complex};
+//! use periodic_array::p_arr;
+//!
+//! let x = p_arr![1.0, 0.0, -1.0, 0.0];
+//! let spectrum = fft::forward_real(&x);
+//! assert!((spectrum[1] - Complex::new(2.0, 0.0)).norm() < 1e-12);
+//! assert!((spectrum[-1] - Complex::new(2.0, 0.0)).norm() < 1e-12);
+//!
+//! let back = fft::inverse_real(&spectrum);
+//! assert!(back.iter().zip(x.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+pub use num_complex::Complex;
+
+use crate::PeriodicArray;
+
+/// Returns the discrete Fourier transform of `input`.
+#[inline]
+pub fn forward<const N: usize>(
+    input: &PeriodicArray<Complex<f64>, N>,
+) -> PeriodicArray<Complex<f64>, N> {
+    let mut output = PeriodicArray::new(input.inner);
+    forward_in_place(&mut output);
+    output
+}
+
+/// Returns the inverse discrete Fourier transform of `input`, normalised by `1 / N`.
+#[inline]
+pub fn inverse<const N: usize>(
+    input: &PeriodicArray<Complex<f64>, N>,
+) -> PeriodicArray<Complex<f64>, N> {
+    let mut output = PeriodicArray::new(input.inner);
+    inverse_in_place(&mut output);
+    output
+}
+
+/// Replaces `data` with its discrete Fourier transform.
+pub fn forward_in_place<const N: usize>(data: &mut PeriodicArray<Complex<f64>, N>) {
+    if N.is_power_of_two() {
+        radix2(&mut data.inner);
+    } else {
+        bluestein(&mut data.inner);
+    }
+}
+
+/// Replaces `data` with its inverse discrete Fourier transform, normalised by `1 / N`.
+pub fn inverse_in_place<const N: usize>(data: &mut PeriodicArray<Complex<f64>, N>) {
+    // conj(DFT(conj(x))) reverses the sign of the exponent
+    data.iter_mut().for_each(|x| *x = x.conj());
+    forward_in_place(data);
+    let scale = 1.0 / N as f64;
+    data.iter_mut().for_each(|x| *x = x.conj() * scale);
+}
+
+/// Returns the discrete Fourier transform of a real signal.
+///
+/// The full spectrum is returned. It is Hermitian, `X[-k] = conj(X[k])`, which
+/// [`inverse_real`] relies on.
+#[inline]
+pub fn forward_real<const N: usize>(
+    input: &PeriodicArray<f64, N>,
+) -> PeriodicArray<Complex<f64>, N> {
+    let mut output = PeriodicArray::new(input.inner.map(|x| Complex::new(x, 0.0)));
+    forward_in_place(&mut output);
+    output
+}
+
+/// Returns the real signal whose spectrum is `input`, normalised by `1 / N`.
+///
+/// Any imaginary part left in the result, which is only rounding noise for a Hermitian
+/// spectrum, is discarded.
+#[inline]
+pub fn inverse_real<const N: usize>(
+    input: &PeriodicArray<Complex<f64>, N>,
+) -> PeriodicArray<f64, N> {
+    PeriodicArray::new(inverse(input).inner.map(|x| x.re))
+}
+
+/// Lengths up to which [`convolve`] and friends evaluate the sums directly, where that is
+/// faster than three transforms.
+const DIRECT_MAX: usize = 64;
+
+/// Returns the circular convolution of `a` and `b`, like
+/// [`circular_convolve`](crate::circular_convolve), in O(N log N) for large `N`.
+///
+/// Lengths up to 64 use the direct O(N²) sum.
+#[inline]
+pub fn convolve<const N: usize>(
+    a: &PeriodicArray<Complex<f64>, N>,
+    b: &PeriodicArray<Complex<f64>, N>,
+) -> PeriodicArray<Complex<f64>, N> {
+    if N <= DIRECT_MAX {
+        return crate::circular_convolve(a, b);
+    }
+    inverse(&spectral_product(&forward(a), &forward(b), false))
+}
+
+/// Returns the circular cross-correlation of `a` and `b`, like
+/// [`circular_correlate`](crate::circular_correlate), in O(N log N) for large `N`.
+///
+/// As there, `a` is not conjugated. Lengths up to 64 use the direct O(N²) sum.
+#[inline]
+pub fn correlate<const N: usize>(
+    a: &PeriodicArray<Complex<f64>, N>,
+    b: &PeriodicArray<Complex<f64>, N>,
+) -> PeriodicArray<Complex<f64>, N> {
+    if N <= DIRECT_MAX {
+        return crate::circular_correlate(a, b);
+    }
+    inverse(&spectral_product(&forward(a), &forward(b), true))
+}
+
+/// Returns the circular convolution of two real signals, in O(N log N) for large `N`.
+///
+/// Lengths up to 64 use the direct O(N²) sum.
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::{fft, PeriodicArray};
+///
+/// let signal = PeriodicArray::<f64, 256>::new(core::array::from_fn(|i| i as f64));
+/// let mut kernel = PeriodicArray::new([0.0; 256]);
+/// kernel[1] = 1.0;
+/// // convolving with a shifted delta rotates the signal
+/// let shifted = fft::convolve_real(&signal, &kernel);
+/// assert!((shifted[0] - 255.0).abs() < 1e-9);
+/// ```
+#[inline]
+pub fn convolve_real<const N: usize>(
+    a: &PeriodicArray<f64, N>,
+    b: &PeriodicArray<f64, N>,
+) -> PeriodicArray<f64, N> {
+    if N <= DIRECT_MAX {
+        return crate::circular_convolve(a, b);
+    }
+    inverse_real(&spectral_product(&forward_real(a), &forward_real(b), false))
+}
+
+/// Returns the circular cross-correlation of two real signals, in O(N log N) for large `N`.
+///
+/// Lengths up to 64 use the direct O(N²) sum.
+#[inline]
+pub fn correlate_real<const N: usize>(
+    a: &PeriodicArray<f64, N>,
+    b: &PeriodicArray<f64, N>,
+) -> PeriodicArray<f64, N> {
+    if N <= DIRECT_MAX {
+        return crate::circular_correlate(a, b);
+    }
+    inverse_real(&spectral_product(&forward_real(a), &forward_real(b), true))
+}
+
+/// Multiplies two spectra bin by bin. Correlation pairs each bin of `b` with the opposite
+/// bin of `a`, the spectrum of `a` reversed.
+#[inline]
+fn spectral_product<const N: usize>(
+    a: &PeriodicArray<Complex<f64>, N>,
+    b: &PeriodicArray<Complex<f64>, N>,
+    reverse_a: bool,
+) -> PeriodicArray<Complex<f64>, N> {
+    PeriodicArray::new(core::array::from_fn(|k| {
+        let ka = if reverse_a { (N - k) % N } else { k };
+        a.inner[ka] * b.inner[k]
+    }))
+}
+
+/// Returns `e^(-2πi k / n)` for every `k` in `0..n / 2`.
+fn twiddles(n: usize) -> Vec<Complex<f64>> {
+    (0..n / 2)
+        .map(|k| Complex::from_polar(1.0, -2.0 * PI * k as f64 / n as f64))
+        .collect()
+}
+
+/// Forward transform of a power-of-two length buffer, in place.
+fn radix2(data: &mut [Complex<f64>]) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let twiddles = twiddles(n);
+    let mut len = 2;
+    while len <= n {
+        let (half, stride) = (len / 2, n / len);
+        for chunk in data.chunks_exact_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for (k, (a, b)) in lo.iter_mut().zip(hi).enumerate() {
+                let t = *b * twiddles[k * stride];
+                *b = *a - t;
+                *a += t;
+            }
+        }
+        len *= 2;
+    }
+}
+
+/// Forward transform of an arbitrary length buffer, in place, via Bluestein's algorithm.
+fn bluestein(data: &mut [Complex<f64>]) {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+
+    // chirp[k] = e^(-πi k² / n); k² is reduced modulo 2n to keep the angle small
+    let chirp: Vec<Complex<f64>> = (0..n)
+        .map(|k| {
+            let k2 = (k as u128 * k as u128 % (2 * n as u128)) as f64;
+            Complex::from_polar(1.0, -PI * k2 / n as f64)
+        })
+        .collect();
+
+    let mut a = vec![Complex::new(0.0, 0.0); m];
+    for ((a, x), w) in a.iter_mut().zip(data.iter()).zip(&chirp) {
+        *a = x * w;
+    }
+    let mut b = vec![Complex::new(0.0, 0.0); m];
+    b[0] = chirp[0].conj();
+    for k in 1..n {
+        b[k] = chirp[k].conj();
+        b[m - k] = chirp[k].conj();
+    }
+
+    // circular convolution of a and b through power-of-two transforms
+    radix2(&mut a);
+    radix2(&mut b);
+    for (a, b) in a.iter_mut().zip(&b) {
+        *a = (*a * b).conj();
+    }
+    radix2(&mut a);
+    let scale = 1.0 / m as f64;
+
+    for ((x, c), w) in data.iter_mut().zip(&a).zip(&chirp) {
+        *x = c.conj() * w * scale;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        convolve, convolve_real, correlate, correlate_real, forward, forward_real, inverse,
+        inverse_real, Complex,
+    };
+    use crate::{circular_convolve, circular_correlate, PeriodicArray};
+
+    fn signal<const N: usize>() -> PeriodicArray<Complex<f64>, N> {
+        PeriodicArray::new(core::array::from_fn(|i| {
+            let t = i as f64;
+            Complex::new((0.7 * t).sin() + 0.1 * t, (1.3 * t).cos() - 0.5)
+        }))
+    }
+
+    fn naive<const N: usize>(x: &PeriodicArray<Complex<f64>, N>) -> [Complex<f64>; N] {
+        core::array::from_fn(|k| {
+            (0..N)
+                .map(|n| {
+                    let angle = -2.0 * core::f64::consts::PI * (k * n % N) as f64 / N as f64;
+                    x[n] * Complex::from_polar(1.0, angle)
+                })
+                .sum()
+        })
+    }
+
+    fn assert_close(a: &[Complex<f64>], b: &[Complex<f64>]) {
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).norm() < 1e-9, "{a} != {b}");
+        }
+    }
+
+    fn check<const N: usize>() {
+        let x = signal::<N>();
+        let spectrum = forward(&x);
+        assert_close(&*spectrum, &naive(&x));
+        assert_close(&*inverse(&spectrum), &*x);
+
+        // Parseval: Σ |x|² = Σ |X|² / N
+        let energy: f64 = x.iter().map(|v| v.norm_sqr()).sum();
+        let spectral: f64 = spectrum.iter().map(|v| v.norm_sqr()).sum::<f64>() / N as f64;
+        assert!((energy - spectral).abs() < 1e-9 * energy);
+    }
+
+    #[test]
+    pub fn agrees_with_naive_dft() {
+        check::<1>();
+        check::<2>();
+        check::<16>();
+        check::<256>();
+        // Bluestein
+        check::<3>();
+        check::<12>();
+        check::<97>();
+        check::<1000>();
+    }
+
+    #[test]
+    pub fn real_round_trip() {
+        let x = PeriodicArray::<f64, 10>::new(core::array::from_fn(|i| (i as f64).sqrt() - 1.0));
+        let spectrum = forward_real(&x);
+
+        for k in 0..10 {
+            assert!((spectrum[k] - spectrum[-k].conj()).norm() < 1e-12);
+        }
+        let back = inverse_real(&spectrum);
+        assert!(back
+            .iter()
+            .zip(x.iter())
+            .all(|(a, b)| (a - b).abs() < 1e-12));
+    }
+
+    #[test]
+    pub fn fast_convolution_agrees_with_direct() {
+        fn check<const N: usize>() {
+            let a = signal::<N>();
+            let b = PeriodicArray::new(a.inner.map(|x| x * x - 1.0));
+            assert_close(&*convolve(&a, &b), &*circular_convolve(&a, &b));
+            assert_close(&*correlate(&a, &b), &*circular_correlate(&a, &b));
+
+            let (a, b) = (
+                PeriodicArray::new(a.inner.map(|x| x.re)),
+                PeriodicArray::new(b.inner.map(|x| x.im)),
+            );
+            let close = |x: &[f64], y: &[f64]| x.iter().zip(y).all(|(x, y)| (x - y).abs() < 1e-9);
+            assert!(close(&*convolve_real(&a, &b), &*circular_convolve(&a, &b)));
+            assert!(close(
+                &*correlate_real(&a, &b),
+                &*circular_correlate(&a, &b)
+            ));
+        }
+        check::<5>();
+        check::<128>();
+        check::<101>();
+    }
+}
@@ -0,0 +1,315 @@
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+
+use crate::PeriodicIndex;
+
+/// A two-dimensional fixed-size grid with independent periodic wrapping along each axis.
+///
+/// Elements are stored contiguously in row-major order, so `grid[[i, j]]` and
+/// `grid[(i, j)]` address row `i` (wrapped by `X`) and column `j` (wrapped by `Y`).
+/// As with [`PeriodicArray`](crate::PeriodicArray), bounds checks are skipped internally
+/// and a zero extent on either axis is a compile-time error.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements held in the grid.
+/// * `X` - The number of rows.
+/// * `Y` - The number of columns.
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::PeriodicGrid2;
+///
+/// let grid = PeriodicGrid2::new([[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(grid[[1, 2]], 6);
+/// assert_eq!(grid[(2, 3)], 1); // Both axes wrap around
+/// assert_eq!(grid[[-1, 0]], 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "copy", derive(Copy))]
+#[repr(C)]
+pub struct PeriodicGrid2<T, const X: usize, const Y: usize> {
+    /// The inner rows.
+    pub(crate) inner: [[T; Y]; X],
+}
+
+impl<T, const X: usize, const Y: usize> PeriodicGrid2<T, X, Y> {
+    #[inline(always)]
+    pub const fn new(inner: [[T; Y]; X]) -> Self {
+        const { assert!(X > 0 && Y > 0, "PeriodicGrid2 must have non-zero extents") };
+        PeriodicGrid2 { inner }
+    }
+
+    /// Returns the elements as a contiguous row-major slice.
+    #[inline(always)]
+    pub fn as_flat(&self) -> &[T] {
+        self.inner.as_flattened()
+    }
+
+    /// Returns the elements as a mutable contiguous row-major slice.
+    #[inline(always)]
+    pub fn as_flat_mut(&mut self) -> &mut [T] {
+        self.inner.as_flattened_mut()
+    }
+}
+
+impl<T, I: PeriodicIndex, const X: usize, const Y: usize> Index<[I; 2]> for PeriodicGrid2<T, X, Y> {
+    type Output = T;
+    #[inline(always)]
+    fn index(&self, [i, j]: [I; 2]) -> &Self::Output {
+        unsafe { self.inner.get_unchecked(i.wrap(X)).get_unchecked(j.wrap(Y)) }
+    }
+}
+
+impl<T, I: PeriodicIndex, const X: usize, const Y: usize> IndexMut<[I; 2]>
+    for PeriodicGrid2<T, X, Y>
+{
+    #[inline(always)]
+    fn index_mut(&mut self, [i, j]: [I; 2]) -> &mut Self::Output {
+        unsafe {
+            self.inner
+                .get_unchecked_mut(i.wrap(X))
+                .get_unchecked_mut(j.wrap(Y))
+        }
+    }
+}
+
+impl<T, I: PeriodicIndex, J: PeriodicIndex, const X: usize, const Y: usize> Index<(I, J)>
+    for PeriodicGrid2<T, X, Y>
+{
+    type Output = T;
+    #[inline(always)]
+    fn index(&self, (i, j): (I, J)) -> &Self::Output {
+        unsafe { self.inner.get_unchecked(i.wrap(X)).get_unchecked(j.wrap(Y)) }
+    }
+}
+
+impl<T, I: PeriodicIndex, J: PeriodicIndex, const X: usize, const Y: usize> IndexMut<(I, J)>
+    for PeriodicGrid2<T, X, Y>
+{
+    #[inline(always)]
+    fn index_mut(&mut self, (i, j): (I, J)) -> &mut Self::Output {
+        unsafe {
+            self.inner
+                .get_unchecked_mut(i.wrap(X))
+                .get_unchecked_mut(j.wrap(Y))
+        }
+    }
+}
+
+impl<T, const X: usize, const Y: usize> Deref for PeriodicGrid2<T, X, Y> {
+    type Target = [[T; Y]; X];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, const X: usize, const Y: usize> DerefMut for PeriodicGrid2<T, X, Y> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T, const X: usize, const Y: usize> From<[[T; Y]; X]> for PeriodicGrid2<T, X, Y> {
+    #[inline(always)]
+    fn from(inner: [[T; Y]; X]) -> Self {
+        PeriodicGrid2::new(inner)
+    }
+}
+
+/// A three-dimensional fixed-size grid with independent periodic wrapping along each axis.
+///
+/// Elements are stored contiguously in row-major order, so `grid[[i, j, k]]` and
+/// `grid[(i, j, k)]` wrap `i` by `X`, `j` by `Y` and `k` by `Z`, with `k` varying fastest.
+/// A zero extent on any axis is a compile-time error.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements held in the grid.
+/// * `X` - The extent of the outermost axis.
+/// * `Y` - The extent of the middle axis.
+/// * `Z` - The extent of the innermost, contiguous axis.
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::PeriodicGrid3;
+///
+/// let grid = PeriodicGrid3::new([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+/// assert_eq!(grid[[1, 0, 1]], 6);
+/// assert_eq!(grid[(-1, 2, 3)], 6); // Every axis wraps around
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "copy", derive(Copy))]
+#[repr(C)]
+pub struct PeriodicGrid3<T, const X: usize, const Y: usize, const Z: usize> {
+    /// The inner planes.
+    pub(crate) inner: [[[T; Z]; Y]; X],
+}
+
+impl<T, const X: usize, const Y: usize, const Z: usize> PeriodicGrid3<T, X, Y, Z> {
+    #[inline(always)]
+    pub const fn new(inner: [[[T; Z]; Y]; X]) -> Self {
+        const {
+            assert!(
+                X > 0 && Y > 0 && Z > 0,
+                "PeriodicGrid3 must have non-zero extents"
+            )
+        };
+        PeriodicGrid3 { inner }
+    }
+
+    /// Returns the elements as a contiguous row-major slice.
+    #[inline(always)]
+    pub fn as_flat(&self) -> &[T] {
+        self.inner.as_flattened().as_flattened()
+    }
+
+    /// Returns the elements as a mutable contiguous row-major slice.
+    #[inline(always)]
+    pub fn as_flat_mut(&mut self) -> &mut [T] {
+        self.inner.as_flattened_mut().as_flattened_mut()
+    }
+}
+
+impl<T, I: PeriodicIndex, const X: usize, const Y: usize, const Z: usize> Index<[I; 3]>
+    for PeriodicGrid3<T, X, Y, Z>
+{
+    type Output = T;
+    #[inline(always)]
+    fn index(&self, [i, j, k]: [I; 3]) -> &Self::Output {
+        unsafe {
+            self.inner
+                .get_unchecked(i.wrap(X))
+                .get_unchecked(j.wrap(Y))
+                .get_unchecked(k.wrap(Z))
+        }
+    }
+}
+
+impl<T, I: PeriodicIndex, const X: usize, const Y: usize, const Z: usize> IndexMut<[I; 3]>
+    for PeriodicGrid3<T, X, Y, Z>
+{
+    #[inline(always)]
+    fn index_mut(&mut self, [i, j, k]: [I; 3]) -> &mut Self::Output {
+        unsafe {
+            self.inner
+                .get_unchecked_mut(i.wrap(X))
+                .get_unchecked_mut(j.wrap(Y))
+                .get_unchecked_mut(k.wrap(Z))
+        }
+    }
+}
+
+impl<
+        T,
+        I: PeriodicIndex,
+        J: PeriodicIndex,
+        K: PeriodicIndex,
+        const X: usize,
+        const Y: usize,
+        const Z: usize,
+    > Index<(I, J, K)> for PeriodicGrid3<T, X, Y, Z>
+{
+    type Output = T;
+    #[inline(always)]
+    fn index(&self, (i, j, k): (I, J, K)) -> &Self::Output {
+        unsafe {
+            self.inner
+                .get_unchecked(i.wrap(X))
+                .get_unchecked(j.wrap(Y))
+                .get_unchecked(k.wrap(Z))
+        }
+    }
+}
+
+impl<
+        T,
+        I: PeriodicIndex,
+        J: PeriodicIndex,
+        K: PeriodicIndex,
+        const X: usize,
+        const Y: usize,
+        const Z: usize,
+    > IndexMut<(I, J, K)> for PeriodicGrid3<T, X, Y, Z>
+{
+    #[inline(always)]
+    fn index_mut(&mut self, (i, j, k): (I, J, K)) -> &mut Self::Output {
+        unsafe {
+            self.inner
+                .get_unchecked_mut(i.wrap(X))
+                .get_unchecked_mut(j.wrap(Y))
+                .get_unchecked_mut(k.wrap(Z))
+        }
+    }
+}
+
+impl<T, const X: usize, const Y: usize, const Z: usize> Deref for PeriodicGrid3<T, X, Y, Z> {
+    type Target = [[[T; Z]; Y]; X];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, const X: usize, const Y: usize, const Z: usize> DerefMut for PeriodicGrid3<T, X, Y, Z> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T, const X: usize, const Y: usize, const Z: usize> From<[[[T; Z]; Y]; X]>
+    for PeriodicGrid3<T, X, Y, Z>
+{
+    #[inline(always)]
+    fn from(inner: [[[T; Z]; Y]; X]) -> Self {
+        PeriodicGrid3::new(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PeriodicGrid2, PeriodicGrid3};
+
+    #[test]
+    pub fn index_grid2() {
+        let mut grid = PeriodicGrid2::new([[1, 2, 3], [4, 5, 6]]);
+
+        // in domain
+        assert_eq!(grid[[0, 0]], 1);
+        assert_eq!(grid[(1, 2)], 6);
+
+        // each axis wraps independently
+        assert_eq!(grid[[2, 1]], 2);
+        assert_eq!(grid[[0, 4]], 2);
+        assert_eq!(grid[(-1, -1)], 6);
+        assert_eq!(grid[(3usize, -3i8)], 4);
+
+        grid[[-1, 3]] = 40;
+        assert_eq!(grid[[1, 0]], 40);
+    }
+
+    #[test]
+    pub fn index_grid3() {
+        let mut grid = PeriodicGrid3::new([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+
+        assert_eq!(grid[[1, 1, 1]], 8);
+        assert_eq!(grid[[2, 3, 4]], 3);
+        assert_eq!(grid[(-1, -1, -1)], 8);
+
+        grid[(0, 0, -1)] = 20;
+        assert_eq!(grid[[0, 0, 1]], 20);
+    }
+
+    #[test]
+    pub fn row_major_layout() {
+        let grid = PeriodicGrid2::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.as_flat(), &[1, 2, 3, 4, 5, 6]);
+
+        let grid = PeriodicGrid3::new([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+        assert_eq!(grid.as_flat(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::FastMod;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An integer type that can be used to index periodic containers.
+///
+/// Implemented for every primitive signed and unsigned integer type. Signed
+/// indices are reduced with Euclidean remainder, so negative indices wrap around
+/// to the end of the period (`-1` is the last element).
+///
+/// This trait is sealed: the unchecked accesses in this crate rely on `wrap`
+/// always returning a value in `0..period`.
+pub trait PeriodicIndex: Copy + private::Sealed {
+    /// Reduces `self` into the range `0..period`.
+    ///
+    /// `period` must be non-zero.
+    fn wrap(self, period: usize) -> usize;
+
+    /// Reduces `self` into the range `0..modulus.period()` without dividing.
+    fn wrap_with(self, modulus: &FastMod) -> usize;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl private::Sealed for $t {}
+
+        impl PeriodicIndex for $t {
+            #[inline(always)]
+            fn wrap(self, period: usize) -> usize {
+                if period <= <$t>::MAX as usize {
+                    (self % period as $t) as usize
+                } else {
+                    // Every value of this type is already smaller than the period.
+                    self as usize
+                }
+            }
+
+            #[inline(always)]
+            fn wrap_with(self, modulus: &FastMod) -> usize {
+                modulus.reduce_wide(self as u128)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl private::Sealed for $t {}
+
+        impl PeriodicIndex for $t {
+            #[inline(always)]
+            fn wrap(self, period: usize) -> usize {
+                if period <= <$t>::MAX as usize {
+                    self.rem_euclid(period as $t) as usize
+                } else if self >= 0 {
+                    self as usize
+                } else {
+                    // The period is larger than |MIN|, so a single period is enough.
+                    period - self.unsigned_abs() as usize
+                }
+            }
+
+            #[inline(always)]
+            fn wrap_with(self, modulus: &FastMod) -> usize {
+                let rem = modulus.reduce_wide(self.unsigned_abs() as u128);
+                if self < 0 && rem != 0 {
+                    modulus.period() - rem
+                } else {
+                    rem
+                }
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::PeriodicIndex;
+    use crate::FastMod;
+
+    #[test]
+    pub fn wrap_unsigned() {
+        assert_eq!(7u8.wrap(3), 1);
+        assert_eq!(u128::MAX.wrap(10), 5);
+        // period wider than the index type
+        assert_eq!(255u8.wrap(1000), 255);
+    }
+
+    #[test]
+    pub fn wrap_signed() {
+        assert_eq!((-1i32).wrap(3), 2);
+        assert_eq!((-3i64).wrap(3), 0);
+        assert_eq!((-7isize).wrap(3), 2);
+        assert_eq!(i128::MIN.wrap(2), 0);
+        // period wider than the index type
+        assert_eq!((-1i8).wrap(200), 199);
+        assert_eq!(i8::MIN.wrap(128), 0);
+        assert_eq!(i8::MAX.wrap(200), 127);
+    }
+
+    #[test]
+    pub fn wrap_with_matches_wrap() {
+        for period in [1, 2, 3, 7, 8, 200, 1000] {
+            let m = FastMod::new(period);
+            for x in [
+                -1000i64,
+                -201,
+                -200,
+                -7,
+                -1,
+                0,
+                1,
+                6,
+                199,
+                1000,
+                i64::MIN,
+                i64::MAX,
+            ] {
+                assert_eq!(x.wrap_with(&m), x.wrap(period), "{x} wrap {period}");
+                assert_eq!((x as i8).wrap_with(&m), (x as i8).wrap(period));
+                assert_eq!((x as u128).wrap_with(&m), (x as u128).wrap(period));
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use crate::PeriodicArray;
+
+/// Splits a position into the wrapped index of the sample at or below it and the fraction
+/// past that sample, in `[0, 1)`.
+#[inline(always)]
+fn split<const N: usize>(x: f64) -> (isize, f64) {
+    // Reduce into one period first, so positions beyond the range of `isize` still wrap.
+    // `%` is exact, but adding `N` back to a tiny negative remainder may round up to `N`.
+    let n = N as f64;
+    let mut x = x % n;
+    if x < 0.0 {
+        x += n;
+    }
+    let i = x as usize;
+    let t = x - i as f64;
+    let i = if i >= N { 0 } else { i as isize };
+    (i, t.min(1.0 - f64::EPSILON / 2.0))
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A floating-point element type that periodic arrays can be interpolated over.
+///
+/// Implemented for `f32` and `f64`. Interpolation weights are computed in `f64`, so `f32`
+/// wavetables do not lose phase precision at large positions.
+///
+/// This trait is sealed.
+pub trait Sample: Copy + private::Sealed {
+    /// Converts from `f64`, rounding if needed.
+    fn from_f64(x: f64) -> Self;
+
+    /// Converts to `f64` losslessly.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_sample {
+    ($($t:ty),*) => {$(
+        impl private::Sealed for $t {}
+
+        impl Sample for $t {
+            #[inline(always)]
+            fn from_f64(x: f64) -> Self {
+                x as $t
+            }
+
+            #[inline(always)]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*};
+}
+
+impl_sample!(f32, f64);
+
+impl<T: Sample, const N: usize> PeriodicArray<T, N> {
+    /// Returns the element nearest to fractional position `x`, rounding halves up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// let pa = p_arr![0.0, 1.0, 2.0, 3.0];
+    /// assert_eq!(pa.sample_nearest(1.4), 1.0);
+    /// assert_eq!(pa.sample_nearest(3.5), 0.0);
+    /// ```
+    #[inline]
+    pub fn sample_nearest(&self, x: f64) -> T {
+        self[split::<N>(x + 0.5).0]
+    }
+
+    /// Returns the value at fractional position `x` by linear interpolation between its two
+    /// neighbouring elements.
+    ///
+    /// Positions wrap like indices do, so negative and multi-period positions are allowed
+    /// and the last element interpolates towards the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// let pa = p_arr![0.0, 1.0, 2.0, 3.0];
+    /// assert_eq!(pa.sample_linear(1.25), 1.25);
+    /// // halfway between the last element and the first
+    /// assert_eq!(pa.sample_linear(3.5), 1.5);
+    /// assert_eq!(pa.sample_linear(-0.5), 1.5);
+    /// ```
+    #[inline]
+    pub fn sample_linear(&self, x: f64) -> T {
+        let (i, t) = split::<N>(x);
+        let (a, b) = (self[i].to_f64(), self[i + 1].to_f64());
+        T::from_f64(a + (b - a) * t)
+    }
+
+    /// Returns the value at fractional position `x` by Catmull-Rom cubic interpolation over
+    /// its four nearest elements.
+    ///
+    /// The curve passes through every element and has a continuous first derivative,
+    /// including across the seam.
+    #[inline]
+    pub fn sample_cubic(&self, x: f64) -> T {
+        let (i, t) = split::<N>(x);
+        let [p0, p1, p2, p3] = [i - 1, i, i + 1, i + 2].map(|j| self[j].to_f64());
+        let c1 = p2 - p0;
+        let c2 = 2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3;
+        let c3 = 3.0 * (p1 - p2) + p3 - p0;
+        T::from_f64(p1 + 0.5 * t * (c1 + t * (c2 + t * c3)))
+    }
+
+    /// Returns the value at fractional position `x` by band-limited (periodic sinc)
+    /// interpolation over every element.
+    ///
+    /// The result is the trigonometric polynomial of lowest degree through all the
+    /// elements, so sampled sinusoids below the Nyquist frequency are reconstructed
+    /// exactly. It costs O(N) per call and requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn sample_sinc(&self, x: f64) -> T {
+        use core::f64::consts::PI;
+
+        let (i, t) = split::<N>(x);
+        if t == 0.0 {
+            return self[i];
+        }
+        let x = i as f64 + t;
+        let n = N as f64;
+        let sum = self
+            .iter()
+            .enumerate()
+            .map(|(k, a)| {
+                let u = PI * (x - k as f64);
+                // The Dirichlet kernel; even lengths split the Nyquist term evenly.
+                let kernel = if N.is_multiple_of(2) {
+                    u.sin() / (n * (u / n).tan())
+                } else {
+                    u.sin() / (n * (u / n).sin())
+                };
+                a.to_f64() * kernel
+            })
+            .sum();
+        T::from_f64(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::p_arr;
+
+    #[test]
+    pub fn linear_wraps_around() {
+        let pa = p_arr![0.0f64, 10.0, 20.0, 30.0, 40.0];
+
+        assert_eq!(pa.sample_linear(2.0), 20.0);
+        assert!((pa.sample_linear(3.7) - 37.0).abs() < 1e-12);
+        assert!((pa.sample_linear(4.5) - 20.0).abs() < 1e-12);
+        assert!((pa.sample_linear(-0.25) - 10.0).abs() < 1e-12);
+        assert!((pa.sample_linear(13.7) - pa.sample_linear(3.7)).abs() < 1e-12);
+        assert!((pa.sample_linear(-6.3) - pa.sample_linear(3.7)).abs() < 1e-12);
+    }
+
+    #[test]
+    pub fn positions_far_from_zero() {
+        let pa = p_arr![0.0f64, 10.0, 20.0, 30.0, 40.0];
+
+        // Adjacent doubles near 1e19 are 2048 apart, and 2048 % 5 == 3.
+        assert_eq!(pa.sample_linear(1e19), 0.0);
+        assert_eq!(pa.sample_linear(1e19 + 2048.0), 30.0);
+        assert_eq!(pa.sample_linear(-1e19 - 2048.0), 20.0);
+        assert_eq!(pa.sample_nearest(-1e19), 0.0);
+
+        // Every double this large is an integer, so each read lands on an element.
+        for x in [1e19, -1e19, 1e300, -1e300, f64::MAX, f64::MIN] {
+            assert!(pa.contains(&pa.sample_linear(x)));
+            assert!(pa.contains(&pa.sample_cubic(x)));
+            assert!(pa.contains(&pa.sample_nearest(x)));
+            #[cfg(feature = "std")]
+            assert!(pa.contains(&pa.sample_sinc(x)));
+        }
+        // A tiny negative position rounds up to a whole period when wrapped.
+        assert_eq!(pa.sample_linear(-1e-300), 0.0);
+    }
+
+    #[test]
+    pub fn cubic_passes_through_elements() {
+        let pa = p_arr![1.0f32, 4.0, -2.0, 0.5, 3.0, 3.0];
+
+        for i in -6..12 {
+            assert_eq!(pa.sample_cubic(i as f64), pa[i]);
+        }
+        // Catmull-Rom reproduces straight lines between interior points
+        let ramp = p_arr![0.0f64, 1.0, 2.0, 3.0, 4.0, 5.0];
+        assert!((ramp.sample_cubic(2.3) - 2.3).abs() < 1e-12);
+        // and is continuous across the seam
+        assert!((pa.sample_cubic(-1e-6) - pa[0]).abs() < 1e-4);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    pub fn sinc_reconstructs_sinusoids() {
+        fn check<const N: usize>() {
+            let w = 2.0 * core::f64::consts::PI / N as f64;
+            let f = |x: f64| (w * x).sin() + 0.5 * (2.0 * w * x + 1.0).cos();
+            let pa = crate::PeriodicArray::<f64, N>::new(core::array::from_fn(|i| f(i as f64)));
+            for x in [-7.3, -0.5, 0.0, 0.25, 2.0, 3.9, 12.6] {
+                assert!((pa.sample_sinc(x) - f(x)).abs() < 1e-9);
+            }
+        }
+        check::<7>();
+        check::<8>();
+    }
+}
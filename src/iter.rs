@@ -0,0 +1,125 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::window::offset;
+use crate::{PeriodicArray, PeriodicIndex, WindowIter};
+
+/// An endless iterator that walks the period over and over, starting anywhere in it.
+///
+/// Created with [`PeriodicArray::cycle_from`].
+#[derive(Debug)]
+pub struct Cycle<'a, T, const N: usize> {
+    data: &'a [T; N],
+    /// Position of the next element, in `0..N`.
+    pos: usize,
+}
+
+impl<T, const N: usize> Clone for Cycle<'_, T, N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Cycle { ..*self }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for Cycle<'a, T, N> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = unsafe { self.data.get_unchecked(self.pos) };
+        self.pos = if self.pos + 1 == N { 0 } else { self.pos + 1 };
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.pos = offset::<N>(self.pos, n);
+        self.next()
+    }
+}
+
+impl<T, const N: usize> FusedIterator for Cycle<'_, T, N> {}
+
+impl<T, const N: usize> PeriodicArray<T, N> {
+    /// Returns an endless iterator over the elements, beginning at the wrapped `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// let pa = p_arr![1, 2, 3];
+    /// assert!(pa.cycle_from(-1).take(5).eq(&[3, 1, 2, 3, 1]));
+    /// ```
+    #[inline]
+    pub fn cycle_from<I: PeriodicIndex>(&self, start: I) -> Cycle<'_, T, N> {
+        Cycle {
+            data: &self.inner,
+            pos: start.wrap(N),
+        }
+    }
+
+    /// Returns an iterator over the elements at every index in `range`, which may be
+    /// negative and may span several periods.
+    ///
+    /// The iterator is double-ended and reports its exact length. An empty or reversed
+    /// range yields nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// let pa = p_arr![1, 2, 3];
+    /// assert!(pa.iter_range(-2..4).eq(&[2, 3, 1, 2, 3, 1]));
+    /// assert!(pa.iter_range(-2..4).rev().eq(&[1, 3, 2, 1, 3, 2]));
+    /// assert_eq!(pa.iter_range(-2..4).len(), 6);
+    /// ```
+    #[inline]
+    pub fn iter_range(&self, range: Range<isize>) -> WindowIter<'_, T, N> {
+        let len = if range.start < range.end {
+            range.end.abs_diff(range.start)
+        } else {
+            0
+        };
+        self.window(range.start, len).iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::p_arr;
+
+    #[test]
+    pub fn cycle_from_start() {
+        let pa = p_arr![1, 2, 3];
+
+        assert!(pa.cycle_from(0).take(7).eq(&[1, 2, 3, 1, 2, 3, 1]));
+        assert!(pa.cycle_from(5u8).take(4).eq(&[3, 1, 2, 3]));
+        assert_eq!(pa.cycle_from(0).nth(100), Some(&pa[100]));
+        assert_eq!(pa.cycle_from(-1).size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    pub fn iter_range_across_periods() {
+        let pa = p_arr![1, 2, 3];
+
+        assert!(pa.iter_range(-7..-1).eq(&[3, 1, 2, 3, 1, 2]));
+        assert!(pa.iter_range(2..3).eq(&[3]));
+        assert_eq!(pa.iter_range(3..3).next(), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = pa.iter_range(3..0);
+        assert_eq!(reversed.len(), 0);
+
+        let mut it = pa.iter_range(-1..5);
+        assert_eq!(it.next(), Some(&3));
+        assert_eq!(it.next_back(), Some(&2));
+        assert_eq!(it.len(), 4);
+        assert!(it.eq(&[1, 2, 3, 1]));
+    }
+}
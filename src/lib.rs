@@ -1,7 +1,58 @@
-use std::ops::{Deref, DerefMut, Index, IndexMut};
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+
+pub mod boundary;
+mod builder;
+mod checked;
+mod construct;
+mod conv;
+mod error;
+mod fastmod;
+#[cfg(feature = "fft")]
+pub mod fft;
+mod grid;
+mod index;
+mod interp;
+mod iter;
+mod ops;
+mod resample;
+mod ring;
+#[cfg(feature = "serde")]
+mod serde;
+mod shift;
+mod slice;
+pub mod spsc;
+pub mod stencil;
+pub mod twist;
+#[cfg(feature = "alloc")]
+mod vec;
+mod window;
+
+pub use conv::{circular_convolve, circular_correlate};
+pub use error::{LengthMismatchError, ZeroLengthError};
+pub use fastmod::FastMod;
+pub use grid::{PeriodicGrid2, PeriodicGrid3};
+pub use index::PeriodicIndex;
+pub use interp::Sample;
+pub use iter::Cycle;
+pub use resample::ResampleMethod;
+pub use ring::{PeriodicRing, RingIter};
+pub use shift::Shifted;
+pub use slice::PeriodicSlice;
+#[cfg(feature = "alloc")]
+pub use vec::PeriodicVec;
+pub use window::{Window, WindowIter};
 
 /// A macro for creating a `PeriodicArray` from a list of elements.
 ///
+/// The macro is usable in `const` and `static` items.
+///
 /// # Examples
 ///
 /// ```
@@ -9,19 +60,151 @@ use std::ops::{Deref, DerefMut, Index, IndexMut};
 ///
 /// let pa = p_arr![1, 2, 3];
 /// ```
+///
+/// `p_arr![value; N]` repeats a `Copy` (or constant) value `N` times:
+///
+/// ```
+/// use periodic_array::p_arr;
+///
+/// let silence = p_arr![0.0; 1024];
+/// assert_eq!(silence[-1], 0.0);
+/// ```
+///
+/// `p_arr![|i| expr; N]` generates `N` elements by evaluating `expr` for every index `i` in
+/// `0..N`. Unlike a closure passed to a function, this also works in constant evaluation:
+///
+/// ```
+/// use periodic_array::{p_arr, PeriodicArray};
+///
+/// static SQUARES: PeriodicArray<u32, 4> = p_arr![|i| (i * i) as u32; 4];
+/// assert_eq!(*SQUARES, [0, 1, 4, 9]);
+/// ```
+///
+/// Any of these forms can be prefixed with the element type, which saves suffixing every
+/// literal. The type is a path with optional single-token generic arguments, such as `f32`
+/// or `Complex<f64>`:
+///
+/// ```
+/// use periodic_array::p_arr;
+///
+/// let table = p_arr![f32: 1.0, 0.5, 0.25];
+/// let zeros = p_arr![u8: 0; 16];
+/// let ramp = p_arr![i64: |i| i as i64 - 2; 5];
+/// assert_eq!(table[3], 1.0f32);
+/// assert_eq!(ramp[0], -2i64);
+/// # assert_eq!(zeros[17], 0u8);
+/// ```
+///
+/// Prefixing rows with `grid:` builds a [`PeriodicGrid2`] from rows of elements, or a
+/// [`PeriodicGrid3`] from planes of rows:
+///
+/// ```
+/// use periodic_array::p_arr;
+///
+/// let g2 = p_arr![grid: [1, 2, 3], [4, 5, 6]];
+/// assert_eq!(g2[[-1, -1]], 6);
+///
+/// let g3 = p_arr![grid: [[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+/// assert_eq!(g3[[1, 0, 3]], 6);
+/// ```
+///
+/// An empty list is rejected at compile time:
+///
+/// ```compile_fail
+/// use periodic_array::{p_arr, PeriodicArray};
+///
+/// let pa: PeriodicArray<u8, 0> = p_arr![];
+/// ```
+///
+/// So is a repeat count of zero:
+///
+/// ```compile_fail
+/// use periodic_array::p_arr;
+///
+/// let pa = p_arr![1u8; 0];
+/// ```
+///
+/// The repeated value must be `Copy` or a constant, as with array repeat expressions:
+///
+/// ```compile_fail
+/// use periodic_array::p_arr;
+///
+/// let pa = p_arr![String::new(); 3];
+/// ```
+///
+/// Elements must match the annotated type:
+///
+/// ```compile_fail
+/// use periodic_array::p_arr;
+///
+/// let pa = p_arr![u8: 1, 2, 300];
+/// ```
+///
+/// And grid rows must all have the same length:
+///
+/// ```compile_fail
+/// use periodic_array::p_arr;
+///
+/// let g = p_arr![grid: [1, 2, 3], [4, 5]];
+/// ```
 #[macro_export]
 macro_rules! p_arr {
+    (grid: $([$([$($x:expr),* $(,)?]),* $(,)?]),+ $(,)?) => {{
+        $crate::PeriodicGrid3::new([$([$([$($x),*]),*]),+])
+    }};
+    (grid: $([$($x:expr),* $(,)?]),+ $(,)?) => {{
+        $crate::PeriodicGrid2::new([$([$($x),*]),+])
+    }};
+    ($($t:ident)::+ $(<$($g:tt),+>)? : $($rest:tt)+) => {{
+        let pa: $crate::PeriodicArray<$($t)::+ $(<$($g),+>)?, _> = $crate::p_arr![$($rest)+];
+        pa
+    }};
+    (|$i:ident| $body:expr; $n:expr) => {{
+        let mut slots = [const { ::core::mem::MaybeUninit::uninit() }; $n];
+        let mut index = 0;
+        while index < $n {
+            let $i: usize = index;
+            slots[index] = ::core::mem::MaybeUninit::new($body);
+            index += 1;
+        }
+        // SAFETY: the loop initialised every slot.
+        $crate::PeriodicArray::new(unsafe { $crate::__private::assume_init_array(slots) })
+    }};
+    ($value:expr; $n:expr) => {{
+        $crate::PeriodicArray::new([$value; $n])
+    }};
     ($($x:expr),* $(,)?) => {{
         $crate::PeriodicArray::new([$($x),*])
     }};
 }
 
+#[doc(hidden)]
+pub mod __private {
+    use core::mem::MaybeUninit;
+
+    /// Converts a fully initialised array of `MaybeUninit<T>` into `[T; N]`.
+    ///
+    /// # Safety
+    ///
+    /// Every element of `slots` must be initialised.
+    #[inline(always)]
+    pub const unsafe fn assume_init_array<T, const N: usize>(slots: [MaybeUninit<T>; N]) -> [T; N] {
+        // `MaybeUninit<T>` has the layout of `T`, and the array is not dropped twice since
+        // `MaybeUninit` never drops its contents.
+        unsafe { core::ptr::read((&raw const slots).cast::<[T; N]>()) }
+    }
+}
+
 /// A struct representing a fixed-size array that provides periodic access to its elements.
 ///
 /// Elements in the array are accessed such that indexing beyond the array's bounds
 /// will wrap around to the beginning, effectively treating the array as infinite/periodic.
+/// Any primitive integer type can be used as an index (see [`PeriodicIndex`]); negative
+/// indices wrap around to the end of the array.
 /// Internally, bounds checks are skipped via the use of `get_unchecked` and `get_unchecked_mut`.
 ///
+/// `N` must be non-zero: constructing a `PeriodicArray` of length zero is a compile-time error.
+///
 /// Copy is optionally derived when the `"copy"` feature is enabled. This separation is done for
 /// those of us that want full control on when copies are performed.
 ///
@@ -38,11 +221,12 @@ macro_rules! p_arr {
 /// let pa = p_arr![1, 2, 3];
 /// assert_eq!(pa[1], 2);
 /// assert_eq!(pa[4], 2); // Access beyond the length wraps around
+/// assert_eq!(pa[-1], 3); // Negative indices wrap around to the end
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "copy", derive(Copy))]
 #[repr(C)]
-pub struct PeriodicArray<T: Clone + Copy, const N: usize> {
+pub struct PeriodicArray<T, const N: usize> {
     /// The inner array.
     ///
     /// Note: This is public so that the `p_arr!` macro can work by explicitly
@@ -50,29 +234,66 @@ pub struct PeriodicArray<T: Clone + Copy, const N: usize> {
     pub(crate) inner: [T; N],
 }
 
-impl<T: Clone + Copy, const N: usize> PeriodicArray<T, N> {
+impl<T, const N: usize> PeriodicArray<T, N> {
+    /// Wraps `inner`, using its length as the period.
+    ///
+    /// A zero-length array fails to compile, as every index would wrap by zero:
+    ///
+    /// ```compile_fail
+    /// use periodic_array::PeriodicArray;
+    ///
+    /// let pa = PeriodicArray::<u8, 0>::new([]);
+    /// ```
     #[inline(always)]
-    pub fn new(inner: [T; N]) -> Self {
+    pub const fn new(inner: [T; N]) -> Self {
+        const { assert!(N > 0, "PeriodicArray must have a non-zero length") };
         PeriodicArray { inner }
     }
+
+    /// Returns the element at the wrapped `index`, in a `const` context if need be.
+    ///
+    /// This is the `const` counterpart of indexing with a `usize`.
+    ///
+    /// # Breaking Change
+    ///
+    /// This method shadows the slice method `get`, which `pa.get(i)` used to reach through
+    /// `Deref` and which returns `None` out of bounds. Such calls still compile but now wrap
+    /// and return `&T`. Use [`get_strict`](PeriodicArray::get_strict) or
+    /// `pa.as_slice().get(i)` for the old `Option` behaviour. [`Window::get`] and
+    /// [`PeriodicRing::get`] are unaffected and still return `Option`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::{p_arr, PeriodicArray};
+    ///
+    /// const TABLE: PeriodicArray<u8, 3> = p_arr![10, 20, 30];
+    /// const WRAPPED: u8 = *TABLE.get(4);
+    /// assert_eq!(WRAPPED, 20);
+    /// ```
+    #[inline(always)]
+    pub const fn get(&self, index: usize) -> &T {
+        // SAFETY: `index % N` is in bounds and `N` is non-zero.
+        unsafe { &*self.inner.as_ptr().add(index % N) }
+    }
 }
 
-impl<T: Clone + Copy, const N: usize> Index<usize> for PeriodicArray<T, N> {
+impl<T, I: PeriodicIndex, const N: usize> Index<I> for PeriodicArray<T, N> {
     type Output = T;
     #[inline(always)]
-    fn index(&self, index: usize) -> &Self::Output {
-        unsafe { self.inner.get_unchecked(index % N) }
+    fn index(&self, index: I) -> &Self::Output {
+        unsafe { self.inner.get_unchecked(index.wrap(N)) }
     }
 }
 
-impl<T: Clone + Copy, const N: usize> IndexMut<usize> for PeriodicArray<T, N> {
+impl<T, I: PeriodicIndex, const N: usize> IndexMut<I> for PeriodicArray<T, N> {
     #[inline(always)]
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        unsafe { self.inner.get_unchecked_mut(index % N) }
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        unsafe { self.inner.get_unchecked_mut(index.wrap(N)) }
     }
 }
 
-impl<T: Clone + Copy, const N: usize> Deref for PeriodicArray<T, N> {
+impl<T, const N: usize> Deref for PeriodicArray<T, N> {
     type Target = [T; N];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
@@ -80,23 +301,23 @@ impl<T: Clone + Copy, const N: usize> Deref for PeriodicArray<T, N> {
     }
 }
 
-impl<T: Clone + Copy, const N: usize> DerefMut for PeriodicArray<T, N> {
+impl<T, const N: usize> DerefMut for PeriodicArray<T, N> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.inner
     }
 }
 
-impl<T: Clone + Copy, const N: usize> From<[T; N]> for PeriodicArray<T, N> {
+impl<T, const N: usize> From<[T; N]> for PeriodicArray<T, N> {
     #[inline(always)]
     fn from(inner: [T; N]) -> Self {
-        PeriodicArray { inner }
+        PeriodicArray::new(inner)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{p_arr, PeriodicArray};
+    use crate::PeriodicArray;
 
     #[test]
     pub fn declare_with_macro() {
@@ -122,6 +343,24 @@ mod tests {
         assert_eq!(pa[5], 3);
     }
 
+    #[test]
+    pub fn index_with_other_integer_types() {
+        let mut pa = p_arr![1, 2, 3];
+
+        // negative indices wrap to the end
+        assert_eq!(pa[-1], 3);
+        assert_eq!(pa[-3], 1);
+        assert_eq!(pa[-4isize], 3);
+        assert_eq!(pa[i128::MIN], pa[i128::MIN.rem_euclid(3) as usize]);
+
+        // unsigned widths
+        assert_eq!(pa[4u8], 2);
+        assert_eq!(pa[u64::MAX], pa[(u64::MAX % 3) as usize]);
+
+        pa[-1i64] = 30;
+        assert_eq!(pa[2usize], 30);
+    }
+
     #[test]
     pub fn use_array_methods() {
         let mut pa = p_arr![1, 2, 3];
@@ -137,4 +376,81 @@ mod tests {
             *p = *p * *p;
         }
     }
+
+    #[test]
+    pub fn heap_owning_elements() {
+        let mut strings = p_arr![String::from("a"), String::from("b")];
+        strings[-1].push('c');
+        assert_eq!(strings[3], "bc");
+        assert_eq!(strings.clone(), strings);
+
+        let vecs = p_arr![vec![1], vec![2, 3]];
+        assert_eq!(vecs[5].len(), 2);
+
+        let boxed: PeriodicArray<Box<dyn Fn(i32) -> i32>, 2> =
+            p_arr![Box::new(|x| x + 1), Box::new(|x| x * 2)];
+        assert_eq!(boxed[0](3), 4);
+        assert_eq!(boxed[-1](3), 6);
+    }
+
+    #[test]
+    pub fn drops_every_element_once() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        struct Counted(Rc<Cell<usize>>);
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut pa =
+            PeriodicArray::new(core::array::from_fn::<_, 4, _>(|_| Counted(drops.clone())));
+
+        // overwriting through a wrapped index drops the old element
+        pa[-1] = Counted(drops.clone());
+        assert_eq!(drops.get(), 1);
+
+        drop(pa);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    pub fn const_construction_and_access() {
+        const CUBES: PeriodicArray<i64, 5> = p_arr![|i| (i as i64).pow(3); 5];
+        static TABLE: PeriodicArray<u8, 4> = p_arr![1, 2, 4, 8];
+        const WRAPPED: u8 = *TABLE.get(6);
+
+        assert_eq!(*CUBES, [0, 1, 8, 27, 64]);
+        assert_eq!(*CUBES.get(usize::MAX), CUBES[usize::MAX]);
+        assert_eq!(WRAPPED, 4);
+
+        // the generator form also accepts non-`Copy` elements at runtime
+        let names = p_arr![|i| format!("item {i}"); 3];
+        assert_eq!(names[-1], "item 2");
+
+        // closures as list elements still take the list form
+        let fns: PeriodicArray<fn(i32) -> i32, 2> = p_arr![|x| x + 1, |x| x * 2];
+        assert_eq!(fns[1](5), 10);
+    }
+
+    #[test]
+    pub fn macro_forms() {
+        const ONES: PeriodicArray<f32, 3> = p_arr![f32: 1.0; 3];
+        static GRID: crate::PeriodicGrid2<u8, 2, 2> = p_arr![grid: [1, 2], [3, 4]];
+
+        assert_eq!(*ONES, [1.0; 3]);
+        assert_eq!(GRID[[3, 2]], 3);
+        assert_eq!(*p_arr![7; 2], [7, 7]);
+        assert_eq!(*p_arr![u64: |i| 1 << i; 4], [1, 2, 4, 8]);
+        assert_eq!(p_arr![core::primitive::i8: -1, 1][1], 1);
+
+        // expressions that merely look like types are still elements
+        let (a, b) = (1, 2);
+        assert_eq!(*p_arr![a < b, a > b], [true, false]);
+        assert_eq!(*p_arr![[1, 2], [3, 4]], [[1, 2], [3, 4]]);
+    }
 }
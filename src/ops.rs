@@ -0,0 +1,298 @@
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::window::add_within;
+use crate::{PeriodicArray, PeriodicIndex};
+
+/// Implements an element-wise binary operator between arrays and between an array and a
+/// scalar, by value and by reference, with its compound assignment form.
+macro_rules! impl_binary_op {
+    ($($op:ident, $method:ident, $op_assign:ident, $method_assign:ident;)*) => {$(
+        impl<T: $op<Output = T>, const N: usize> $op for PeriodicArray<T, N> {
+            type Output = PeriodicArray<T, N>;
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                let mut rhs = rhs.inner.into_iter();
+                // SAFETY: both arrays hold exactly `N` elements.
+                PeriodicArray::new(
+                    self.inner
+                        .map(|a| a.$method(unsafe { rhs.next().unwrap_unchecked() })),
+                )
+            }
+        }
+
+        impl<T: Clone + $op<Output = T>, const N: usize> $op for &PeriodicArray<T, N> {
+            type Output = PeriodicArray<T, N>;
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                PeriodicArray::new(core::array::from_fn(|i| {
+                    self.inner[i].clone().$method(rhs.inner[i].clone())
+                }))
+            }
+        }
+
+        impl<T: Clone + $op<Output = T>, const N: usize> $op<T> for PeriodicArray<T, N> {
+            type Output = PeriodicArray<T, N>;
+            #[inline]
+            fn $method(self, rhs: T) -> Self::Output {
+                PeriodicArray::new(self.inner.map(|a| a.$method(rhs.clone())))
+            }
+        }
+
+        impl<T: Clone + $op<Output = T>, const N: usize> $op<T> for &PeriodicArray<T, N> {
+            type Output = PeriodicArray<T, N>;
+            #[inline]
+            fn $method(self, rhs: T) -> Self::Output {
+                PeriodicArray::new(core::array::from_fn(|i| {
+                    self.inner[i].clone().$method(rhs.clone())
+                }))
+            }
+        }
+
+        impl<T: $op_assign, const N: usize> $op_assign for PeriodicArray<T, N> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: Self) {
+                for (a, b) in self.inner.iter_mut().zip(rhs.inner) {
+                    a.$method_assign(b);
+                }
+            }
+        }
+
+        impl<T: Clone + $op_assign, const N: usize> $op_assign<&PeriodicArray<T, N>>
+            for PeriodicArray<T, N>
+        {
+            #[inline]
+            fn $method_assign(&mut self, rhs: &PeriodicArray<T, N>) {
+                for (a, b) in self.inner.iter_mut().zip(&rhs.inner) {
+                    a.$method_assign(b.clone());
+                }
+            }
+        }
+
+        impl<T: Clone + $op_assign, const N: usize> $op_assign<T> for PeriodicArray<T, N> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: T) {
+                for a in &mut self.inner {
+                    a.$method_assign(rhs.clone());
+                }
+            }
+        }
+    )*};
+}
+
+impl_binary_op! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+}
+
+/// Implements `scalar op array` for primitive scalars, which the orphan rules do not allow
+/// generically.
+macro_rules! impl_scalar_lhs {
+    ($($t:ty),*) => {$(
+        impl<const N: usize> Add<PeriodicArray<$t, N>> for $t {
+            type Output = PeriodicArray<$t, N>;
+            #[inline]
+            fn add(self, rhs: PeriodicArray<$t, N>) -> Self::Output {
+                PeriodicArray::new(rhs.inner.map(|b| self + b))
+            }
+        }
+
+        impl<const N: usize> Sub<PeriodicArray<$t, N>> for $t {
+            type Output = PeriodicArray<$t, N>;
+            #[inline]
+            fn sub(self, rhs: PeriodicArray<$t, N>) -> Self::Output {
+                PeriodicArray::new(rhs.inner.map(|b| self - b))
+            }
+        }
+
+        impl<const N: usize> Mul<PeriodicArray<$t, N>> for $t {
+            type Output = PeriodicArray<$t, N>;
+            #[inline]
+            fn mul(self, rhs: PeriodicArray<$t, N>) -> Self::Output {
+                PeriodicArray::new(rhs.inner.map(|b| self * b))
+            }
+        }
+
+        impl<const N: usize> Div<PeriodicArray<$t, N>> for $t {
+            type Output = PeriodicArray<$t, N>;
+            #[inline]
+            fn div(self, rhs: PeriodicArray<$t, N>) -> Self::Output {
+                PeriodicArray::new(rhs.inner.map(|b| self / b))
+            }
+        }
+    )*};
+}
+
+impl_scalar_lhs!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<T: Neg<Output = T>, const N: usize> Neg for PeriodicArray<T, N> {
+    type Output = PeriodicArray<T, N>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        PeriodicArray::new(self.inner.map(Neg::neg))
+    }
+}
+
+impl<T: Clone + Neg<Output = T>, const N: usize> Neg for &PeriodicArray<T, N> {
+    type Output = PeriodicArray<T, N>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        PeriodicArray::new(core::array::from_fn(|i| -self.inner[i].clone()))
+    }
+}
+
+/// Sums arrays element-wise. The sum of no arrays is filled with the element type's zero.
+impl<T: Sum + AddAssign, const N: usize> Sum for PeriodicArray<T, N> {
+    fn sum<It: Iterator<Item = Self>>(iter: It) -> Self {
+        let zero = PeriodicArray::new(core::array::from_fn(|_| core::iter::empty().sum()));
+        iter.fold(zero, |mut acc, x| {
+            acc += x;
+            acc
+        })
+    }
+}
+
+impl<'a, T: Clone + Sum + AddAssign, const N: usize> Sum<&'a PeriodicArray<T, N>>
+    for PeriodicArray<T, N>
+{
+    fn sum<It: Iterator<Item = &'a Self>>(iter: It) -> Self {
+        let zero = PeriodicArray::new(core::array::from_fn(|_| core::iter::empty().sum()));
+        iter.fold(zero, |mut acc, x| {
+            acc += x;
+            acc
+        })
+    }
+}
+
+/// Multiplies arrays element-wise. The product of no arrays is filled with the element
+/// type's one.
+impl<T: Product + MulAssign, const N: usize> Product for PeriodicArray<T, N> {
+    fn product<It: Iterator<Item = Self>>(iter: It) -> Self {
+        let one = PeriodicArray::new(core::array::from_fn(|_| core::iter::empty().product()));
+        iter.fold(one, |mut acc, x| {
+            acc *= x;
+            acc
+        })
+    }
+}
+
+impl<'a, T: Clone + Product + MulAssign, const N: usize> Product<&'a PeriodicArray<T, N>>
+    for PeriodicArray<T, N>
+{
+    fn product<It: Iterator<Item = &'a Self>>(iter: It) -> Self {
+        let one = PeriodicArray::new(core::array::from_fn(|_| core::iter::empty().product()));
+        iter.fold(one, |mut acc, x| {
+            acc *= x;
+            acc
+        })
+    }
+}
+
+impl<T, const N: usize> PeriodicArray<T, N> {
+    /// Returns the dot product `Σ self[i] * other[i]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// assert_eq!(p_arr![1, 2, 3].dot(&p_arr![4, 5, 6]), 32);
+    /// ```
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T
+    where
+        T: Clone + Add<Output = T> + Mul<Output = T>,
+    {
+        let term = |i: usize| self.inner[i].clone() * other.inner[i].clone();
+        (1..N).fold(term(0), |acc, i| acc + term(i))
+    }
+
+    /// Returns the dot product of `self` with `other` shifted by `shift`,
+    /// `Σ self[i] * other[i + shift]`.
+    ///
+    /// This is one lag of [`circular_correlate`](crate::circular_correlate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// assert_eq!(p_arr![1, 0, 0].dot_shifted(&p_arr![4, 5, 6], -1), 6);
+    /// ```
+    #[inline]
+    pub fn dot_shifted<I: PeriodicIndex>(&self, other: &Self, shift: I) -> T
+    where
+        T: Clone + Add<Output = T> + Mul<Output = T>,
+    {
+        let shift = shift.wrap(N);
+        let term = |i: usize| {
+            let j = add_within::<N>(i, shift);
+            self.inner[i].clone() * unsafe { other.inner.get_unchecked(j).clone() }
+        };
+        (1..N).fold(term(0), |acc, i| acc + term(i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{p_arr, PeriodicArray};
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn element_wise_between_arrays() {
+        let a = p_arr![1.0, 2.0, 3.0];
+        let b = p_arr![4.0, 8.0, 12.0];
+
+        assert_eq!(*(&a + &b), [5.0, 10.0, 15.0]);
+        assert_eq!(*(&b - &a), [3.0, 6.0, 9.0]);
+        assert_eq!(*(&a * &b), [4.0, 16.0, 36.0]);
+        assert_eq!(*(&b / &a), [4.0, 4.0, 4.0]);
+        assert_eq!(*-&a, [-1.0, -2.0, -3.0]);
+        assert_eq!(*(a + b), [5.0, 10.0, 15.0]);
+
+        let mut c = p_arr![1, 2, 3];
+        c += p_arr![1, 1, 1];
+        c *= &p_arr![2, 3, 4];
+        c -= p_arr![0, 1, 2];
+        c /= &p_arr![2, 4, 6];
+        assert_eq!(*c, [2, 2, 2]);
+        assert_eq!(*-c, [-2, -2, -2]);
+    }
+
+    #[test]
+    pub fn element_wise_with_scalars() {
+        let a = p_arr![1, 2, 3];
+
+        assert_eq!(*(&a + 1), [2, 3, 4]);
+        assert_eq!(*(&a * 2), [2, 4, 6]);
+        assert_eq!(*(10i32 - p_arr![1, 2, 3]), [9, 8, 7]);
+        assert_eq!(*(0.5f64 * p_arr![2.0, 4.0]), [1.0, 2.0]);
+
+        let mut b = p_arr![1.5, 3.0];
+        b /= 1.5;
+        b -= 1.0;
+        assert_eq!(*b, [0.0, 1.0]);
+    }
+
+    #[test]
+    pub fn sum_product_and_dot() {
+        let arrays = [p_arr![1, 2], p_arr![3, 4], p_arr![5, 6]];
+
+        assert_eq!(*arrays.iter().sum::<PeriodicArray<i32, 2>>(), [9, 12]);
+        assert_eq!(
+            *arrays.into_iter().product::<PeriodicArray<i32, 2>>(),
+            [15, 48]
+        );
+        let none: [PeriodicArray<f64, 3>; 0] = [];
+        assert_eq!(*none.iter().sum::<PeriodicArray<f64, 3>>(), [0.0; 3]);
+        assert_eq!(*none.iter().product::<PeriodicArray<f64, 3>>(), [1.0; 3]);
+
+        let a = p_arr![1, 2, 3];
+        let b = p_arr![4, 5, 6];
+        assert_eq!(a.dot(&b), 32);
+        assert_eq!(a.dot_shifted(&b, 1), 4 * 3 + 5 + 6 * 2);
+        assert_eq!(a.dot_shifted(&b, 3), a.dot(&b));
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{PeriodicArray, Sample};
+
+/// How [`PeriodicArray::resample`] computes values between the original elements.
+///
+/// Non-exhaustive because enabling the `fft` feature adds a variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResampleMethod {
+    /// Takes the nearest original element.
+    Nearest,
+    /// Interpolates linearly, see [`PeriodicArray::sample_linear`].
+    Linear,
+    /// Interpolates with Catmull-Rom splines, see [`PeriodicArray::sample_cubic`].
+    Cubic,
+    /// Zero-pads or truncates the spectrum, which is exact for band-limited signals and
+    /// removes frequencies the output cannot represent when downsampling.
+    ///
+    /// Requires the `fft` feature.
+    #[cfg(feature = "fft")]
+    Spectral,
+}
+
+impl<T: Sample, const N: usize> PeriodicArray<T, N> {
+    /// Returns the period resampled onto `M` evenly spaced points.
+    ///
+    /// Output element `j` lies at position `j * N / M` of `self`, so the first elements
+    /// coincide and the output wraps around exactly where the input does.
+    ///
+    /// [`Nearest`](ResampleMethod::Nearest), [`Linear`](ResampleMethod::Linear) and
+    /// [`Cubic`](ResampleMethod::Cubic) read individual positions and do not low-pass filter,
+    /// so downsampling with them may alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::{p_arr, PeriodicArray, ResampleMethod};
+    ///
+    /// let pa = p_arr![0.0, 2.0, 4.0, 2.0];
+    /// let up: PeriodicArray<f64, 8> = pa.resample(ResampleMethod::Linear);
+    /// assert_eq!(*up, [0.0, 1.0, 2.0, 3.0, 4.0, 3.0, 2.0, 1.0]);
+    /// ```
+    pub fn resample<const M: usize>(&self, method: ResampleMethod) -> PeriodicArray<T, M> {
+        let step = N as f64 / M as f64;
+        let position = |j: usize| j as f64 * step;
+        match method {
+            ResampleMethod::Nearest => {
+                PeriodicArray::new(core::array::from_fn(|j| self.sample_nearest(position(j))))
+            }
+            ResampleMethod::Linear => {
+                PeriodicArray::new(core::array::from_fn(|j| self.sample_linear(position(j))))
+            }
+            ResampleMethod::Cubic => {
+                PeriodicArray::new(core::array::from_fn(|j| self.sample_cubic(position(j))))
+            }
+            #[cfg(feature = "fft")]
+            ResampleMethod::Spectral => self.resample_spectral(),
+        }
+    }
+
+    #[cfg(feature = "fft")]
+    fn resample_spectral<const M: usize>(&self) -> PeriodicArray<T, M> {
+        use crate::fft::{self, Complex};
+
+        let input = PeriodicArray::new(self.inner.map(Sample::to_f64));
+        let x = fft::forward_real(&input);
+        let mut y = PeriodicArray::new([Complex::new(0.0, 0.0); M]);
+
+        // Copy every frequency both lengths can represent, scaled for the new length.
+        let scale = M as f64 / N as f64;
+        let shared = N.min(M);
+        for k in 0..shared.div_ceil(2) {
+            y[k] = x[k] * scale;
+            y[M - k] = x[N - k] * scale;
+        }
+        // With an even shared length, the Nyquist bin is the sum of a frequency and its
+        // negative, which are separate bins in the longer spectrum.
+        if shared.is_multiple_of(2) {
+            let h = shared / 2;
+            if N > M {
+                y[h] = (x[h] + x[N - h]) * scale;
+            } else if N < M {
+                y[h] = x[h] * (scale / 2.0);
+                y[M - h] = x[h] * (scale / 2.0);
+            } else {
+                y[h] = x[h];
+            }
+        }
+
+        PeriodicArray::new(fft::inverse_real(&y).inner.map(T::from_f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PeriodicArray, ResampleMethod};
+
+    fn wave<const N: usize>() -> PeriodicArray<f64, N> {
+        let w = 2.0 * core::f64::consts::PI / N as f64;
+        PeriodicArray::new(core::array::from_fn(|i| {
+            let x = i as f64 * w;
+            (x + 0.3).sin() + 0.25 * (3.0 * x).cos()
+        }))
+    }
+
+    /// Asserts that the step across the seam is no larger than any step inside the period.
+    fn assert_continuous<const M: usize>(out: &PeriodicArray<f64, M>) {
+        let largest = (1..M)
+            .map(|i| (out[i] - out[i - 1]).abs())
+            .fold(0.0, f64::max);
+        assert!((out[0] - out[-1]).abs() <= largest * 1.01);
+    }
+
+    #[test]
+    pub fn upsampling_keeps_original_elements() {
+        let pa = wave::<16>();
+        for method in [
+            ResampleMethod::Nearest,
+            ResampleMethod::Linear,
+            ResampleMethod::Cubic,
+        ] {
+            let up: PeriodicArray<f64, 64> = pa.resample(method);
+            for i in 0..16 {
+                assert_eq!(up[4 * i], pa[i]);
+            }
+            assert_continuous(&up);
+        }
+    }
+
+    #[test]
+    pub fn downsampling_is_continuous() {
+        let pa = wave::<64>();
+        for method in [
+            ResampleMethod::Nearest,
+            ResampleMethod::Linear,
+            ResampleMethod::Cubic,
+        ] {
+            let down: PeriodicArray<f64, 24> = pa.resample(method);
+            assert_continuous(&down);
+        }
+        let same: PeriodicArray<f32, 3> =
+            PeriodicArray::new([1.0, 2.0, 3.0]).resample(ResampleMethod::Nearest);
+        assert_eq!(*same, [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "fft")]
+    pub fn spectral_is_exact_for_band_limited_signals() {
+        let close = |a: &[f64], b: &[f64]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-9);
+
+        let up: PeriodicArray<f64, 100> = wave::<16>().resample(ResampleMethod::Spectral);
+        assert!(close(&*up, &*wave::<100>()));
+        assert_continuous(&up);
+
+        let down: PeriodicArray<f64, 7> = wave::<64>().resample(ResampleMethod::Spectral);
+        assert!(close(&*down, &*wave::<7>()));
+
+        let same: PeriodicArray<f64, 10> = wave::<10>().resample(ResampleMethod::Spectral);
+        assert!(close(&*same, &*wave::<10>()));
+
+        // An even number of samples per period is exactly the Nyquist frequency.
+        let nyquist = PeriodicArray::new([1.0, -1.0]);
+        let up: PeriodicArray<f64, 4> = nyquist.resample(ResampleMethod::Spectral);
+        assert!(close(&*up, &[1.0, 0.0, -1.0, 0.0]));
+    }
+}
@@ -0,0 +1,335 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem::MaybeUninit;
+use core::ops::{Index, IndexMut};
+
+use crate::{PeriodicArray, WindowIter};
+
+/// A fixed-capacity circular buffer built on [`PeriodicArray`] storage.
+///
+/// Elements are pushed at the back and popped from the front. Once the ring holds `N`
+/// elements, [`push_back`](PeriodicRing::push_back) overwrites the oldest one, which makes
+/// it a natural fit for rolling windows over a stream of samples.
+///
+/// Indices are logical: `ring[0]` is the oldest element and `ring[ring.len() - 1]` the
+/// newest. They are checked against the current length and then wrapped onto the storage
+/// through its periodic indexing.
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::PeriodicRing;
+///
+/// let mut ring = PeriodicRing::<i32, 3>::new();
+/// for sample in 1..=5 {
+///     ring.push_back(sample);
+/// }
+/// assert_eq!(ring.len(), 3);
+/// assert_eq!(ring[0], 3);
+/// assert!(ring.iter().eq(&[3, 4, 5]));
+/// assert_eq!(ring.pop_front(), Some(3));
+/// ```
+pub struct PeriodicRing<T, const N: usize> {
+    storage: PeriodicArray<MaybeUninit<T>, N>,
+    /// Storage position of the oldest element, in `0..N`.
+    head: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> PeriodicRing<T, N> {
+    /// Creates an empty ring with capacity `N`.
+    #[inline]
+    pub fn new() -> Self {
+        PeriodicRing {
+            storage: PeriodicArray::new([const { MaybeUninit::uninit() }; N]),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the ring.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the ring holds no elements.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the next push will overwrite the oldest element.
+    #[inline(always)]
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the maximum number of elements the ring can hold, `N`.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Appends `value` as the newest element.
+    ///
+    /// If the ring is full, the oldest element is overwritten and returned.
+    #[inline]
+    pub fn push_back(&mut self, value: T) -> Option<T> {
+        if self.is_full() {
+            let slot = &mut self.storage[self.head];
+            // SAFETY: the ring is full, so the oldest slot is initialised.
+            let oldest = unsafe { slot.assume_init_read() };
+            slot.write(value);
+            self.head = if self.head + 1 == N { 0 } else { self.head + 1 };
+            Some(oldest)
+        } else {
+            self.storage[self.head + self.len].write(value);
+            self.len += 1;
+            None
+        }
+    }
+
+    /// Removes and returns the oldest element, or `None` if the ring is empty.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: the ring is not empty, so the oldest slot is initialised.
+        let oldest = unsafe { self.storage[self.head].assume_init_read() };
+        self.head = if self.head + 1 == N { 0 } else { self.head + 1 };
+        self.len -= 1;
+        Some(oldest)
+    }
+
+    /// Returns the element at logical `index`, or `None` if `index >= len`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            // SAFETY: logical indices below `len` are initialised.
+            Some(unsafe { self.storage[self.head + index].assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the element at logical `index` mutably, or `None` if `index >= len`.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            // SAFETY: logical indices below `len` are initialised.
+            Some(unsafe { self.storage[self.head + index].assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the oldest element, or `None` if the ring is empty.
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns the newest element, or `None` if the ring is empty.
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns an iterator over the elements from oldest to newest.
+    #[inline]
+    pub fn iter(&self) -> RingIter<'_, T, N> {
+        RingIter {
+            inner: self.storage.window(self.head, self.len).iter(),
+        }
+    }
+
+    /// Drops every element, leaving the ring empty.
+    #[inline]
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T, const N: usize> Default for PeriodicRing<T, N> {
+    #[inline]
+    fn default() -> Self {
+        PeriodicRing::new()
+    }
+}
+
+impl<T, const N: usize> Drop for PeriodicRing<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for PeriodicRing<T, N> {
+    fn clone(&self) -> Self {
+        let mut ring = PeriodicRing::new();
+        for value in self {
+            ring.push_back(value.clone());
+        }
+        ring
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for PeriodicRing<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for PeriodicRing<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for PeriodicRing<T, N> {}
+
+impl<T, const N: usize> Index<usize> for PeriodicRing<T, N> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!("index {index} out of range for ring of length {len}"),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for PeriodicRing<T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("index {index} out of range for ring of length {len}"),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a PeriodicRing<T, N> {
+    type Item = &'a T;
+    type IntoIter = RingIter<'a, T, N>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`PeriodicRing`], from oldest to newest.
+///
+/// Created with [`PeriodicRing::iter`].
+pub struct RingIter<'a, T, const N: usize> {
+    inner: WindowIter<'a, MaybeUninit<T>, N>,
+}
+
+impl<T, const N: usize> Clone for RingIter<'_, T, N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        RingIter {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for RingIter<'a, T, N> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: the iterator only covers the ring's initialised slots.
+        self.inner
+            .next()
+            .map(|slot| unsafe { slot.assume_init_ref() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for RingIter<'_, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: the iterator only covers the ring's initialised slots.
+        self.inner
+            .next_back()
+            .map(|slot| unsafe { slot.assume_init_ref() })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for RingIter<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for RingIter<'_, T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::PeriodicRing;
+
+    #[test]
+    pub fn push_overwrites_oldest() {
+        let mut ring = PeriodicRing::<i32, 3>::new();
+
+        assert_eq!(ring.push_back(1), None);
+        assert_eq!(ring.push_back(2), None);
+        assert_eq!(ring.push_back(3), None);
+        assert!(ring.is_full());
+        assert_eq!(ring.push_back(4), Some(1));
+        assert_eq!(ring.push_back(5), Some(2));
+
+        assert!(ring.iter().eq(&[3, 4, 5]));
+        assert!(ring.iter().rev().eq(&[5, 4, 3]));
+        assert_eq!((ring[0], ring[2]), (3, 5));
+        assert_eq!((ring.front(), ring.back()), (Some(&3), Some(&5)));
+        assert_eq!(ring.get(3), None);
+    }
+
+    #[test]
+    pub fn pop_in_logical_order() {
+        let mut ring = PeriodicRing::<i32, 3>::new();
+        for x in 0..7 {
+            ring.push_back(x);
+        }
+
+        assert_eq!(ring.pop_front(), Some(4));
+        ring.push_back(7);
+        ring[0] = 50;
+        assert_eq!(ring.pop_front(), Some(50));
+        assert_eq!(ring.pop_front(), Some(6));
+        assert_eq!(ring.pop_front(), Some(7));
+        assert_eq!(ring.pop_front(), None);
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    pub fn drops_live_elements() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut ring = PeriodicRing::<Rc<()>, 2>::new();
+        for _ in 0..5 {
+            ring.push_back(tracker.clone());
+        }
+        assert_eq!(Rc::strong_count(&tracker), 3);
+
+        let cloned = ring.clone();
+        assert_eq!(cloned, ring);
+        drop(ring);
+        drop(cloned);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn index_past_len_panics() {
+        let mut ring = PeriodicRing::<i32, 3>::new();
+        ring.push_back(1);
+        let _ = ring[1];
+    }
+}
@@ -0,0 +1,107 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use crate::builder::ArrayBuilder;
+use crate::PeriodicArray;
+
+/// Serializes the elements as a plain sequence of length `N`.
+impl<T: Serialize, const N: usize> Serialize for PeriodicArray<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(N))?;
+        for element in &self.inner {
+            seq.serialize_element(element)?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes a sequence, failing unless it holds exactly `N` elements.
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for PeriodicArray<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(PeriodicArrayVisitor(PhantomData))
+    }
+}
+
+struct PeriodicArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for PeriodicArrayVisitor<T, N> {
+    type Value = PeriodicArray<T, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of exactly {N} elements")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut builder = ArrayBuilder::<T, N>::new();
+        while builder.len() < N {
+            match seq.next_element()? {
+                Some(element) => builder.push(element),
+                None => return Err(de::Error::invalid_length(builder.len(), &self)),
+            }
+        }
+
+        // Count any surplus so the error reports the actual length.
+        let mut len = N;
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            len += 1;
+        }
+        if len > N {
+            return Err(de::Error::invalid_length(len, &self));
+        }
+
+        match builder.build() {
+            Some(inner) => Ok(PeriodicArray::new(inner)),
+            None => unreachable!("builder holds N elements"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{p_arr, PeriodicArray};
+
+    #[test]
+    pub fn json_round_trip() {
+        let pa = p_arr![1.5, -2.0, 3.25];
+
+        let json = serde_json::to_string(&pa).unwrap();
+        assert_eq!(json, "[1.5,-2.0,3.25]");
+        assert_eq!(
+            serde_json::from_str::<PeriodicArray<f64, 3>>(&json).unwrap(),
+            pa
+        );
+    }
+
+    #[test]
+    pub fn binary_round_trip() {
+        let pa = p_arr![1u32, 2, 3, 4];
+
+        let bytes = postcard::to_allocvec(&pa).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<PeriodicArray<u32, 4>>(&bytes).unwrap(),
+            pa
+        );
+        // a length-prefixed sequence of a different length is rejected
+        assert!(postcard::from_bytes::<PeriodicArray<u32, 3>>(&bytes).is_err());
+    }
+
+    #[test]
+    pub fn length_mismatch_is_descriptive() {
+        let err = serde_json::from_str::<PeriodicArray<u8, 3>>("[1, 2]").unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid length 2, expected a sequence of exactly 3 elements"),
+            "{err}"
+        );
+
+        let err = serde_json::from_str::<PeriodicArray<u8, 3>>("[1, 2, 3, 4, 5]").unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid length 5, expected a sequence of exactly 3 elements"),
+            "{err}"
+        );
+    }
+}
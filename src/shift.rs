@@ -0,0 +1,171 @@
+use core::fmt;
+use core::ops::Index;
+
+use crate::window::add_within;
+use crate::{PeriodicArray, PeriodicIndex, WindowIter};
+
+/// A zero-copy view of a [`PeriodicArray`] with every index shifted by a fixed offset.
+///
+/// `shifted[i]` reads `array[i + offset]`, so the view looks like the array rotated left
+/// by `offset` without moving any element. Shifting a view again only adds to its offset,
+/// making repeated phase shifts O(1).
+///
+/// Created with [`PeriodicArray::shifted`].
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::p_arr;
+///
+/// let pa = p_arr![1, 2, 3, 4];
+/// let s = pa.shifted(1);
+/// assert_eq!(s[0], 2);
+/// assert_eq!(s[-1], 1);
+/// assert_eq!(s.shifted(-3)[0], 3);
+/// ```
+pub struct Shifted<'a, T, const N: usize> {
+    array: &'a PeriodicArray<T, N>,
+    /// The offset, in `0..N`.
+    offset: usize,
+}
+
+impl<'a, T, const N: usize> Shifted<'a, T, N> {
+    /// Returns the offset added to every index, in `0..N`.
+    #[inline(always)]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns a view shifted by a further `k` positions.
+    #[inline]
+    pub fn shifted<I: PeriodicIndex>(&self, k: I) -> Shifted<'a, T, N> {
+        Shifted {
+            array: self.array,
+            offset: add_within::<N>(self.offset, k.wrap(N)),
+        }
+    }
+
+    /// Returns an iterator over one period of the view, starting at index `0`.
+    #[inline]
+    pub fn iter(&self) -> WindowIter<'a, T, N> {
+        self.array.window(self.offset, N).iter()
+    }
+
+    /// Copies the view into a new array, rotated so that index `0` of the view comes first.
+    #[inline]
+    pub fn to_array(&self) -> PeriodicArray<T, N>
+    where
+        T: Clone,
+    {
+        let mut inner = self.array.inner.clone();
+        inner.rotate_left(self.offset);
+        PeriodicArray::new(inner)
+    }
+}
+
+impl<T, const N: usize> Clone for Shifted<'_, T, N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for Shifted<'_, T, N> {}
+
+impl<T, I: PeriodicIndex, const N: usize> Index<I> for Shifted<'_, T, N> {
+    type Output = T;
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        let i = add_within::<N>(self.offset, index.wrap(N));
+        unsafe { self.array.inner.get_unchecked(i) }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Shifted<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for Shifted<'_, T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for Shifted<'_, T, N> {}
+
+impl<T, const N: usize> PeriodicArray<T, N> {
+    /// Rotates the elements in place so that the element at index `k` moves to index `0`.
+    ///
+    /// Any integer offset is accepted and reduced modulo `N`, so negative offsets rotate
+    /// right. This moves every element; use [`shifted`](PeriodicArray::shifted) for an O(1) view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// let mut pa = p_arr![1, 2, 3, 4];
+    /// pa.rotate(1);
+    /// assert_eq!(*pa, [2, 3, 4, 1]);
+    /// pa.rotate(-6);
+    /// assert_eq!(*pa, [4, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn rotate<I: PeriodicIndex>(&mut self, k: I) {
+        self.inner.rotate_left(k.wrap(N));
+    }
+
+    /// Returns a view in which index `i` reads the element at `i + k`, without moving any element.
+    #[inline]
+    pub fn shifted<I: PeriodicIndex>(&self, k: I) -> Shifted<'_, T, N> {
+        Shifted {
+            array: self,
+            offset: k.wrap(N),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::p_arr;
+
+    #[test]
+    pub fn rotate_by_any_offset() {
+        let mut pa = p_arr![1, 2, 3, 4, 5];
+
+        pa.rotate(2);
+        assert_eq!(*pa, [3, 4, 5, 1, 2]);
+        pa.rotate(-2isize);
+        assert_eq!(*pa, [1, 2, 3, 4, 5]);
+        pa.rotate(isize::MIN);
+        assert_eq!(pa[0], p_arr![1, 2, 3, 4, 5][isize::MIN]);
+    }
+
+    #[test]
+    pub fn shifted_matches_rotate() {
+        let pa = p_arr![1, 2, 3, 4, 5];
+
+        for k in -12isize..12 {
+            let mut rotated = p_arr![1, 2, 3, 4, 5];
+            rotated.rotate(k);
+
+            let view = pa.shifted(k);
+            assert_eq!(view.to_array(), rotated);
+            assert!(view.iter().eq(rotated.iter()));
+            for i in -6..6 {
+                assert_eq!(view[i], rotated[i]);
+            }
+        }
+    }
+
+    #[test]
+    pub fn shifts_compose() {
+        let pa = p_arr![1, 2, 3, 4, 5];
+        let view = pa.shifted(3).shifted(4).shifted(-1);
+
+        assert_eq!(view.offset(), 1);
+        assert_eq!(view, pa.shifted(6));
+    }
+}
@@ -0,0 +1,136 @@
+use core::ops::{Deref, Index};
+
+use crate::{FastMod, PeriodicArray, PeriodicIndex, ZeroLengthError};
+
+/// A borrowed view of a non-empty slice with periodic access to its elements.
+///
+/// This is the borrowed counterpart of `PeriodicVec` and can be obtained from any
+/// [`PeriodicArray`] or `PeriodicVec` without copying. It wraps indices with a
+/// precomputed [`FastMod`]. It does not allocate, so it is available without the `alloc`
+/// feature.
+///
+/// # Zero Length
+///
+/// As with `PeriodicVec`, the view is never empty: [`PeriodicSlice::new`] panics when
+/// given an empty slice, while [`PeriodicSlice::try_new`] returns a [`ZeroLengthError`].
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::{p_arr, PeriodicSlice};
+///
+/// let pa = p_arr![1, 2, 3];
+/// let ps = pa.as_periodic_slice();
+/// assert_eq!(ps[5], 3);
+///
+/// let ps = PeriodicSlice::new(&pa.as_slice()[1..]);
+/// assert_eq!(ps[2], 2);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PeriodicSlice<'a, T> {
+    pub(crate) inner: &'a [T],
+    pub(crate) modulus: FastMod,
+}
+
+impl<'a, T> PeriodicSlice<'a, T> {
+    /// Wraps `inner`, using its length as the period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inner` is empty.
+    #[inline]
+    pub fn new(inner: &'a [T]) -> Self {
+        assert!(!inner.is_empty(), "PeriodicSlice must not be empty");
+        let modulus = FastMod::new(inner.len());
+        PeriodicSlice { inner, modulus }
+    }
+
+    /// Wraps `inner`, using its length as the period, or fails if `inner` is empty.
+    #[inline]
+    pub fn try_new(inner: &'a [T]) -> Result<Self, ZeroLengthError> {
+        if inner.is_empty() {
+            return Err(ZeroLengthError);
+        }
+        let modulus = FastMod::new(inner.len());
+        Ok(PeriodicSlice { inner, modulus })
+    }
+
+    /// Returns the underlying slice with the lifetime of the borrow.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &'a [T] {
+        self.inner
+    }
+}
+
+impl<T> Clone for PeriodicSlice<'_, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for PeriodicSlice<'_, T> {}
+
+impl<T, I: PeriodicIndex> Index<I> for PeriodicSlice<'_, T> {
+    type Output = T;
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        unsafe { self.inner.get_unchecked(index.wrap_with(&self.modulus)) }
+    }
+}
+
+impl<T> Deref for PeriodicSlice<'_, T> {
+    type Target = [T];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<'a, T, const N: usize> From<&'a PeriodicArray<T, N>> for PeriodicSlice<'a, T> {
+    #[inline(always)]
+    fn from(array: &'a PeriodicArray<T, N>) -> Self {
+        array.as_periodic_slice()
+    }
+}
+
+impl<T, const N: usize> PeriodicArray<T, N> {
+    /// Borrows the elements as a [`PeriodicSlice`].
+    #[inline(always)]
+    pub fn as_periodic_slice(&self) -> PeriodicSlice<'_, T> {
+        PeriodicSlice {
+            inner: &self.inner,
+            modulus: const { FastMod::new(N) },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{p_arr, PeriodicSlice, ZeroLengthError};
+
+    #[test]
+    pub fn index_into_slice() {
+        let data = [1, 2, 3, 4];
+        let ps = PeriodicSlice::new(&data[..3]);
+
+        assert_eq!(ps.len(), 3);
+        assert_eq!(ps[3], 1);
+        assert_eq!(ps[-2], 2);
+
+        let pa = p_arr![1, 2, 3];
+        assert_eq!(PeriodicSlice::from(&pa)[-1], 3);
+    }
+
+    #[test]
+    pub fn try_new_rejects_empty() {
+        assert_eq!(PeriodicSlice::<u8>::try_new(&[]), Err(ZeroLengthError));
+        assert_eq!(PeriodicSlice::try_new(&[1]).map(|ps| ps[7]), Ok(1));
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn empty_slice_panics() {
+        PeriodicSlice::<u8>::new(&[]);
+    }
+}
@@ -0,0 +1,491 @@
+//! A wait-free single-producer, single-consumer queue over periodic storage.
+//!
+//! A [`Queue`] owns a fixed [`PeriodicArray`] of slots and two atomic counters. Splitting it
+//! yields one [`Producer`] and one [`Consumer`], which may live on different threads. Each
+//! operation is a bounded number of atomic loads and stores, with no locks and no retries.
+//!
+//! The counters run over `0..2N` rather than `0..N`, so a full queue and an empty queue are
+//! told apart without sacrificing a slot; a counter is turned into a slot through the
+//! storage's own periodic indexing.
+//!
+//! # Examples
+//!
+//! ```
+//! use periodic_array::spsc::Queue;
+//!
+//! let mut queue = Queue::<u32, 4>::new();
+//! let (mut producer, mut consumer) = queue.split();
+//!
+//! std::thread::scope(|s| {
+//!     s.spawn(move || {
+//!         for i in 0..100 {
+//!             while producer.push(i).is_err() {
+//!                 std::thread::yield_now();
+//!             }
+//!         }
+//!     });
+//!     for i in 0..100 {
+//!         loop {
+//!             if let Some(value) = consumer.pop() {
+//!                 assert_eq!(value, i);
+//!                 break;
+//!             }
+//!             std::thread::yield_now();
+//!         }
+//!     }
+//! });
+//! ```
+
+use core::mem::MaybeUninit;
+use core::ops::Range;
+
+use crate::PeriodicArray;
+
+use self::sync::{AtomicUsize, Ordering, UnsafeCell};
+
+#[cfg(loom)]
+mod sync {
+    pub(super) use loom::cell::UnsafeCell;
+    pub(super) use loom::sync::atomic::{AtomicUsize, Ordering};
+}
+
+#[cfg(not(loom))]
+mod sync {
+    pub(super) use core::sync::atomic::{AtomicUsize, Ordering};
+
+    /// `core::cell::UnsafeCell` behind the closure-based interface loom checks accesses with.
+    pub(super) struct UnsafeCell<T>(core::cell::UnsafeCell<T>);
+
+    impl<T> UnsafeCell<T> {
+        #[inline(always)]
+        pub(super) fn new(value: T) -> Self {
+            UnsafeCell(core::cell::UnsafeCell::new(value))
+        }
+
+        #[inline(always)]
+        pub(super) fn with<R>(&self, f: impl FnOnce(*const T) -> R) -> R {
+            f(self.0.get())
+        }
+
+        #[inline(always)]
+        pub(super) fn with_mut<R>(&self, f: impl FnOnce(*mut T) -> R) -> R {
+            f(self.0.get())
+        }
+    }
+}
+
+/// A fixed-capacity queue shared by one [`Producer`] and one [`Consumer`].
+///
+/// Created empty with [`Queue::new`] and divided into its two handles with [`Queue::split`].
+/// Elements still queued when the `Queue` is dropped are dropped with it.
+pub struct Queue<T, const N: usize> {
+    slots: PeriodicArray<UnsafeCell<MaybeUninit<T>>, N>,
+    /// Counter of the next slot to read, in `0..2N`. Written only by the consumer.
+    head: AtomicUsize,
+    /// Counter of the next slot to write, in `0..2N`. Written only by the producer.
+    tail: AtomicUsize,
+}
+
+// SAFETY: a slot is only ever accessed by the handle that currently owns it, as decided
+// by the acquire/release handoff on `head` and `tail`.
+unsafe impl<T: Send, const N: usize> Sync for Queue<T, N> {}
+
+impl<T, const N: usize> Queue<T, N> {
+    /// Creates an empty queue with capacity `N`.
+    pub fn new() -> Self {
+        const { assert!(N <= usize::MAX / 2, "spsc::Queue capacity is too large") };
+        Queue {
+            slots: PeriodicArray::new(core::array::from_fn(|_| {
+                UnsafeCell::new(MaybeUninit::uninit())
+            })),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    /// Splits the queue into its producer and consumer handles.
+    ///
+    /// The exclusive borrow guarantees there is only ever one of each.
+    pub fn split(&mut self) -> (Producer<'_, T, N>, Consumer<'_, T, N>) {
+        let queue: &Self = self;
+        (Producer { queue }, Consumer { queue })
+    }
+
+    /// Returns the maximum number of elements the queue can hold, `N`.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of queued elements between counters `head` and `tail`.
+    #[inline(always)]
+    fn distance(head: usize, tail: usize) -> usize {
+        if tail >= head {
+            tail - head
+        } else {
+            tail + (2 * N - head)
+        }
+    }
+
+    /// Advances `counter` by `k <= N` positions, wrapping at `2N`.
+    #[inline(always)]
+    fn advance(counter: usize, k: usize) -> usize {
+        let to_wrap = 2 * N - counter;
+        if k < to_wrap {
+            counter + k
+        } else {
+            k - to_wrap
+        }
+    }
+
+    /// Splits the `len` slots starting at `counter` into the runs before and after the seam.
+    #[inline(always)]
+    fn runs(counter: usize, len: usize) -> (Range<usize>, Range<usize>) {
+        let start = if counter >= N { counter - N } else { counter };
+        let first = len.min(N - start);
+        (start..start + first, 0..len - first)
+    }
+}
+
+impl<T, const N: usize> Default for Queue<T, N> {
+    fn default() -> Self {
+        Queue::new()
+    }
+}
+
+impl<T, const N: usize> Drop for Queue<T, N> {
+    fn drop(&mut self) {
+        let mut head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Relaxed);
+        while head != tail {
+            // SAFETY: slots between `head` and `tail` are initialised, and we have `&mut self`.
+            self.slots[head].with_mut(|slot| unsafe { (*slot).assume_init_drop() });
+            head = Self::advance(head, 1);
+        }
+    }
+}
+
+/// The sending half of a [`Queue`].
+pub struct Producer<'a, T, const N: usize> {
+    queue: &'a Queue<T, N>,
+}
+
+impl<T, const N: usize> Producer<'_, T, N> {
+    /// Enqueues `value`, handing it back if the queue is full.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let queue = self.queue;
+        let tail = queue.tail.load(Ordering::Relaxed);
+        let head = queue.head.load(Ordering::Acquire);
+        if Queue::<T, N>::distance(head, tail) == N {
+            return Err(value);
+        }
+        // SAFETY: the slot at `tail` is free and only the producer writes free slots.
+        queue.slots[tail].with_mut(|slot| unsafe { (*slot).write(value) });
+        queue
+            .tail
+            .store(Queue::<T, N>::advance(tail, 1), Ordering::Release);
+        Ok(())
+    }
+
+    /// Enqueues as many leading elements of `values` as fit, returning how many were copied.
+    ///
+    /// The elements are written as at most two contiguous runs, split at the seam, and
+    /// published to the consumer with a single store.
+    #[inline]
+    pub fn push_slice(&mut self, values: &[T]) -> usize
+    where
+        T: Copy,
+    {
+        let queue = self.queue;
+        let tail = queue.tail.load(Ordering::Relaxed);
+        let head = queue.head.load(Ordering::Acquire);
+        let count = values.len().min(N - Queue::<T, N>::distance(head, tail));
+
+        let (first, second) = Queue::<T, N>::runs(tail, count);
+        for (i, value) in first.chain(second).zip(values) {
+            // SAFETY: the run lies within the free slots and `i < N`.
+            let cell = unsafe { queue.slots.inner.get_unchecked(i) };
+            cell.with_mut(|slot| unsafe { (*slot).write(*value) });
+        }
+        queue
+            .tail
+            .store(Queue::<T, N>::advance(tail, count), Ordering::Release);
+        count
+    }
+
+    /// Returns the number of queued elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if no elements are queued.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the next push will fail.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+}
+
+/// The receiving half of a [`Queue`].
+pub struct Consumer<'a, T, const N: usize> {
+    queue: &'a Queue<T, N>,
+}
+
+impl<T, const N: usize> Consumer<'_, T, N> {
+    /// Dequeues the oldest element, or returns `None` if the queue is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let queue = self.queue;
+        let head = queue.head.load(Ordering::Relaxed);
+        let tail = queue.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        // SAFETY: the slot at `head` was published by the producer and is read only once.
+        let value = queue.slots[head].with(|slot| unsafe { (*slot).assume_init_read() });
+        queue
+            .head
+            .store(Queue::<T, N>::advance(head, 1), Ordering::Release);
+        Some(value)
+    }
+
+    /// Dequeues up to `out.len()` of the oldest elements into `out`, returning how many
+    /// were copied.
+    ///
+    /// The elements are read as at most two contiguous runs, split at the seam, and their
+    /// slots are released to the producer with a single store.
+    #[inline]
+    pub fn pop_slice(&mut self, out: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        let queue = self.queue;
+        let head = queue.head.load(Ordering::Relaxed);
+        let tail = queue.tail.load(Ordering::Acquire);
+        let count = out.len().min(Queue::<T, N>::distance(head, tail));
+
+        let (first, second) = Queue::<T, N>::runs(head, count);
+        for (i, value) in first.chain(second).zip(out) {
+            // SAFETY: the run lies within the published slots and `i < N`.
+            let cell = unsafe { queue.slots.inner.get_unchecked(i) };
+            *value = cell.with(|slot| unsafe { (*slot).assume_init_read() });
+        }
+        queue
+            .head
+            .store(Queue::<T, N>::advance(head, count), Ordering::Release);
+        count
+    }
+
+    /// Returns a reference to the oldest element without dequeuing it.
+    ///
+    /// This takes `&mut self` because a `Consumer` is `Sync`: handing out `&T` through a
+    /// shared reference would let two threads reach the same element at once, even when
+    /// `T` is not `Sync`.
+    ///
+    /// ```compile_fail,E0596
+    /// use std::cell::Cell;
+    /// use periodic_array::spsc::Queue;
+    ///
+    /// let mut queue = Queue::<Cell<u32>, 2>::new();
+    /// let (mut producer, consumer) = queue.split();
+    /// producer.push(Cell::new(0)).ok();
+    /// let consumer = &consumer;
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| consumer.peek().unwrap().set(1));
+    ///     consumer.peek().unwrap().set(2);
+    /// });
+    /// ```
+    #[inline]
+    pub fn peek(&mut self) -> Option<&T> {
+        let queue = self.queue;
+        let head = queue.head.load(Ordering::Relaxed);
+        let tail = queue.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        // SAFETY: the slot stays initialised until this consumer pops it, which cannot happen
+        // while the returned reference borrows `self` mutably.
+        Some(queue.slots[head].with(|slot| unsafe { (*slot).assume_init_ref() }))
+    }
+
+    /// Returns the number of queued elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if no elements are queued.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T, const N: usize> Queue<T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+        Self::distance(head, tail)
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::Queue;
+
+    #[test]
+    pub fn push_pop_in_order() {
+        let mut queue = Queue::<i32, 3>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        for round in 0..5 {
+            assert_eq!(producer.push(round), Ok(()));
+            assert_eq!(producer.push(round + 1), Ok(()));
+            assert_eq!(producer.push(round + 2), Ok(()));
+            assert_eq!(producer.push(99), Err(99));
+            assert!(producer.is_full());
+
+            assert_eq!(consumer.peek(), Some(&round));
+            assert_eq!(consumer.pop(), Some(round));
+            assert_eq!(consumer.pop(), Some(round + 1));
+            assert_eq!(consumer.pop(), Some(round + 2));
+            assert_eq!(consumer.pop(), None);
+        }
+    }
+
+    #[test]
+    pub fn slices_cross_the_seam() {
+        let mut queue = Queue::<u8, 4>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        assert_eq!(producer.push_slice(&[1, 2, 3]), 3);
+        let mut out = [0; 2];
+        assert_eq!(consumer.pop_slice(&mut out), 2);
+        assert_eq!(out, [1, 2]);
+
+        // wraps around the end of the storage, and only fits three
+        assert_eq!(producer.push_slice(&[4, 5, 6, 7, 8]), 3);
+        assert_eq!(consumer.len(), 4);
+
+        let mut out = [0; 8];
+        assert_eq!(consumer.pop_slice(&mut out), 4);
+        assert_eq!(out[..4], [3, 4, 5, 6]);
+        assert!(consumer.is_empty());
+    }
+
+    #[test]
+    pub fn drops_queued_elements() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut queue = Queue::<Rc<()>, 4>::new();
+        let (mut producer, mut consumer) = queue.split();
+        for _ in 0..3 {
+            producer.push(tracker.clone()).unwrap();
+        }
+        drop(consumer.pop());
+
+        drop(queue);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    pub fn threads_exchange_in_order() {
+        let mut queue = Queue::<usize, 8>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        std::thread::scope(|s| {
+            s.spawn(move || {
+                let mut next = 0;
+                while next < 10_000 {
+                    let batch: Vec<usize> = (next..(next + 5).min(10_000)).collect();
+                    next += producer.push_slice(&batch);
+                    std::thread::yield_now();
+                }
+            });
+
+            let mut expected = 0;
+            let mut out = [0; 3];
+            while expected < 10_000 {
+                let n = consumer.pop_slice(&mut out);
+                for &value in &out[..n] {
+                    assert_eq!(value, expected);
+                    expected += 1;
+                }
+                std::thread::yield_now();
+            }
+        });
+    }
+}
+
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use super::Queue;
+    use loom::thread;
+
+    #[test]
+    pub fn push_pop_concurrently() {
+        loom::model(|| {
+            let queue: &'static mut Queue<usize, 2> = Box::leak(Box::new(Queue::new()));
+            let (mut producer, mut consumer) = queue.split();
+
+            let handle = thread::spawn(move || {
+                for i in 0..3 {
+                    while producer.push(i).is_err() {
+                        thread::yield_now();
+                    }
+                }
+            });
+
+            for i in 0..3 {
+                loop {
+                    match consumer.pop() {
+                        Some(value) => {
+                            assert_eq!(value, i);
+                            break;
+                        }
+                        None => thread::yield_now(),
+                    }
+                }
+            }
+            handle.join().unwrap();
+        });
+    }
+
+    #[test]
+    pub fn slices_concurrently() {
+        loom::model(|| {
+            let queue: &'static mut Queue<usize, 2> = Box::leak(Box::new(Queue::new()));
+            let (mut producer, mut consumer) = queue.split();
+
+            let handle = thread::spawn(move || {
+                let values = [0, 1, 2];
+                let mut sent = 0;
+                while sent < values.len() {
+                    sent += producer.push_slice(&values[sent..]);
+                    thread::yield_now();
+                }
+            });
+
+            let mut received = 0;
+            let mut out = [0; 2];
+            while received < 3 {
+                let n = consumer.pop_slice(&mut out);
+                for &value in &out[..n] {
+                    assert_eq!(value, received);
+                    received += 1;
+                }
+                thread::yield_now();
+            }
+            handle.join().unwrap();
+        });
+    }
+}
@@ -0,0 +1,463 @@
+//! Finite-difference stencils over periodic domains.
+//!
+//! A stencil is a fixed set of coefficients applied to consecutive neighbours of every
+//! element. Since neighbours are looked up with periodic indexing, the first and last
+//! elements need no special treatment: the domain is a ring (or a torus for grids).
+//!
+//! Stencils with `f32` and `f64` coefficients come with the usual difference operators on a
+//! unit grid; [`Stencil::scaled`] divides them by a grid spacing.
+//!
+//! # Examples
+//!
+//! ```
+//! use periodic_array::p_arr;
+//! use periodic_array::stencil::Stencil;
+//!
+//! let u = p_arr![0.0, 1.0, 4.0, 9.0];
+//! let lap = Stencil::<f64, 3>::laplacian().apply(&u);
+//! assert_eq!(*lap, [10.0, 2.0, 2.0, -14.0]);
+//! ```
+
+use core::ops::{Add, Mul};
+
+use crate::window::add_within;
+use crate::{PeriodicArray, PeriodicGrid2, PeriodicGrid3, PeriodicIndex};
+
+/// A one-dimensional stencil of `K` coefficients.
+///
+/// Applying it computes `out[i] = coeffs[0] * a[i + offset] + ... + coeffs[K - 1] * a[i + offset + K - 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stencil<C, const K: usize> {
+    /// The position, relative to the output element, of the neighbour `coeffs[0]` applies to.
+    pub offset: isize,
+    /// The coefficients, for consecutive neighbours.
+    pub coeffs: [C; K],
+}
+
+impl<C, const K: usize> Stencil<C, K> {
+    /// Creates a stencil whose first coefficient applies `offset` elements away.
+    #[inline]
+    pub fn new(offset: isize, coeffs: [C; K]) -> Self {
+        const { assert!(K > 0, "Stencil must have at least one coefficient") };
+        Stencil { offset, coeffs }
+    }
+
+    /// Multiplies every coefficient by `factor`, e.g. `1.0 / h` for a grid spacing of `h`.
+    #[inline]
+    pub fn scaled(self, factor: C) -> Self
+    where
+        C: Copy + Mul<Output = C>,
+    {
+        Stencil {
+            offset: self.offset,
+            coeffs: self.coeffs.map(|c| c * factor),
+        }
+    }
+
+    /// Applies the stencil to every element of `input`, writing the results to `output`.
+    #[inline]
+    pub fn apply_into<T, const N: usize>(
+        &self,
+        input: &PeriodicArray<T, N>,
+        output: &mut PeriodicArray<T, N>,
+    ) where
+        C: Copy,
+        T: Copy + Add<Output = T> + Mul<C, Output = T>,
+    {
+        let shifts = shifts::<N, K>(self.offset);
+        for (i, out) in output.inner.iter_mut().enumerate() {
+            *out = self.at(input, i, &shifts);
+        }
+    }
+
+    /// Applies the stencil to every element of `input`, returning the results.
+    #[inline]
+    pub fn apply<T, const N: usize>(&self, input: &PeriodicArray<T, N>) -> PeriodicArray<T, N>
+    where
+        C: Copy,
+        T: Copy + Add<Output = T> + Mul<C, Output = T>,
+    {
+        let shifts = shifts::<N, K>(self.offset);
+        PeriodicArray::new(core::array::from_fn(|i| self.at(input, i, &shifts)))
+    }
+
+    #[inline(always)]
+    fn at<T, const N: usize>(&self, input: &PeriodicArray<T, N>, i: usize, shifts: &[usize; K]) -> T
+    where
+        C: Copy,
+        T: Copy + Add<Output = T> + Mul<C, Output = T>,
+    {
+        let tap = |k: usize| {
+            let j = add_within::<N>(i, shifts[k]);
+            unsafe { *input.inner.get_unchecked(j) * self.coeffs[k] }
+        };
+        (1..K).fold(tap(0), |acc, k| acc + tap(k))
+    }
+}
+
+/// Returns `(offset + k) mod N` for every coefficient `k`.
+#[inline(always)]
+fn shifts<const N: usize, const K: usize>(offset: isize) -> [usize; K] {
+    let base = offset.wrap(N);
+    core::array::from_fn(|k| add_within::<N>(base, k % N))
+}
+
+/// A two-dimensional stencil of `KX` by `KY` coefficients, for [`PeriodicGrid2`].
+///
+/// Applying it computes `out[[i, j]]` as the sum of `coeffs[a][b] * g[[i + offset[0] + a, j + offset[1] + b]]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stencil2<C, const KX: usize, const KY: usize> {
+    /// The position, relative to the output element, of the neighbour `coeffs[0][0]` applies to.
+    pub offset: [isize; 2],
+    /// The coefficients, in row-major order.
+    pub coeffs: [[C; KY]; KX],
+}
+
+impl<C, const KX: usize, const KY: usize> Stencil2<C, KX, KY> {
+    /// Creates a stencil whose first coefficient applies at `offset` from the output element.
+    #[inline]
+    pub fn new(offset: [isize; 2], coeffs: [[C; KY]; KX]) -> Self {
+        const {
+            assert!(
+                KX > 0 && KY > 0,
+                "Stencil2 must have at least one coefficient"
+            )
+        };
+        Stencil2 { offset, coeffs }
+    }
+
+    /// Multiplies every coefficient by `factor`.
+    #[inline]
+    pub fn scaled(self, factor: C) -> Self
+    where
+        C: Copy + Mul<Output = C>,
+    {
+        Stencil2 {
+            offset: self.offset,
+            coeffs: self.coeffs.map(|row| row.map(|c| c * factor)),
+        }
+    }
+
+    /// Applies the stencil to every element of `input`, writing the results to `output`.
+    #[inline]
+    pub fn apply_into<T, const X: usize, const Y: usize>(
+        &self,
+        input: &PeriodicGrid2<T, X, Y>,
+        output: &mut PeriodicGrid2<T, X, Y>,
+    ) where
+        C: Copy,
+        T: Copy + Add<Output = T> + Mul<C, Output = T>,
+    {
+        let (sx, sy) = (
+            shifts::<X, KX>(self.offset[0]),
+            shifts::<Y, KY>(self.offset[1]),
+        );
+        for (i, row) in output.inner.iter_mut().enumerate() {
+            for (j, out) in row.iter_mut().enumerate() {
+                *out = self.at(input, i, j, &sx, &sy);
+            }
+        }
+    }
+
+    /// Applies the stencil to every element of `input`, returning the results.
+    #[inline]
+    pub fn apply<T, const X: usize, const Y: usize>(
+        &self,
+        input: &PeriodicGrid2<T, X, Y>,
+    ) -> PeriodicGrid2<T, X, Y>
+    where
+        C: Copy,
+        T: Copy + Add<Output = T> + Mul<C, Output = T>,
+    {
+        let (sx, sy) = (
+            shifts::<X, KX>(self.offset[0]),
+            shifts::<Y, KY>(self.offset[1]),
+        );
+        PeriodicGrid2::new(core::array::from_fn(|i| {
+            core::array::from_fn(|j| self.at(input, i, j, &sx, &sy))
+        }))
+    }
+
+    #[inline(always)]
+    fn at<T, const X: usize, const Y: usize>(
+        &self,
+        input: &PeriodicGrid2<T, X, Y>,
+        i: usize,
+        j: usize,
+        sx: &[usize; KX],
+        sy: &[usize; KY],
+    ) -> T
+    where
+        C: Copy,
+        T: Copy + Add<Output = T> + Mul<C, Output = T>,
+    {
+        let tap = |a: usize, b: usize| {
+            let (x, y) = (add_within::<X>(i, sx[a]), add_within::<Y>(j, sy[b]));
+            unsafe { *input.inner.get_unchecked(x).get_unchecked(y) * self.coeffs[a][b] }
+        };
+        (1..KX * KY).fold(tap(0, 0), |acc, n| acc + tap(n / KY, n % KY))
+    }
+}
+
+/// A three-dimensional stencil of `KX` by `KY` by `KZ` coefficients, for [`PeriodicGrid3`].
+///
+/// Applying it computes `out[[i, j, k]]` as the sum of
+/// `coeffs[a][b][c] * g[[i + offset[0] + a, j + offset[1] + b, k + offset[2] + c]]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stencil3<C, const KX: usize, const KY: usize, const KZ: usize> {
+    /// The position, relative to the output element, of the neighbour `coeffs[0][0][0]` applies to.
+    pub offset: [isize; 3],
+    /// The coefficients, in row-major order.
+    pub coeffs: [[[C; KZ]; KY]; KX],
+}
+
+impl<C, const KX: usize, const KY: usize, const KZ: usize> Stencil3<C, KX, KY, KZ> {
+    /// Creates a stencil whose first coefficient applies at `offset` from the output element.
+    #[inline]
+    pub fn new(offset: [isize; 3], coeffs: [[[C; KZ]; KY]; KX]) -> Self {
+        const {
+            assert!(
+                KX > 0 && KY > 0 && KZ > 0,
+                "Stencil3 must have at least one coefficient"
+            )
+        };
+        Stencil3 { offset, coeffs }
+    }
+
+    /// Multiplies every coefficient by `factor`.
+    #[inline]
+    pub fn scaled(self, factor: C) -> Self
+    where
+        C: Copy + Mul<Output = C>,
+    {
+        Stencil3 {
+            offset: self.offset,
+            coeffs: self
+                .coeffs
+                .map(|plane| plane.map(|row| row.map(|c| c * factor))),
+        }
+    }
+
+    /// Applies the stencil to every element of `input`, writing the results to `output`.
+    #[inline]
+    pub fn apply_into<T, const X: usize, const Y: usize, const Z: usize>(
+        &self,
+        input: &PeriodicGrid3<T, X, Y, Z>,
+        output: &mut PeriodicGrid3<T, X, Y, Z>,
+    ) where
+        C: Copy,
+        T: Copy + Add<Output = T> + Mul<C, Output = T>,
+    {
+        let shifts = (
+            shifts::<X, KX>(self.offset[0]),
+            shifts::<Y, KY>(self.offset[1]),
+            shifts::<Z, KZ>(self.offset[2]),
+        );
+        for (i, plane) in output.inner.iter_mut().enumerate() {
+            for (j, row) in plane.iter_mut().enumerate() {
+                for (k, out) in row.iter_mut().enumerate() {
+                    *out = self.at(input, [i, j, k], &shifts);
+                }
+            }
+        }
+    }
+
+    /// Applies the stencil to every element of `input`, returning the results.
+    #[inline]
+    pub fn apply<T, const X: usize, const Y: usize, const Z: usize>(
+        &self,
+        input: &PeriodicGrid3<T, X, Y, Z>,
+    ) -> PeriodicGrid3<T, X, Y, Z>
+    where
+        C: Copy,
+        T: Copy + Add<Output = T> + Mul<C, Output = T>,
+    {
+        let shifts = (
+            shifts::<X, KX>(self.offset[0]),
+            shifts::<Y, KY>(self.offset[1]),
+            shifts::<Z, KZ>(self.offset[2]),
+        );
+        PeriodicGrid3::new(core::array::from_fn(|i| {
+            core::array::from_fn(|j| core::array::from_fn(|k| self.at(input, [i, j, k], &shifts)))
+        }))
+    }
+
+    #[inline(always)]
+    #[allow(clippy::type_complexity)]
+    fn at<T, const X: usize, const Y: usize, const Z: usize>(
+        &self,
+        input: &PeriodicGrid3<T, X, Y, Z>,
+        [i, j, k]: [usize; 3],
+        (sx, sy, sz): &([usize; KX], [usize; KY], [usize; KZ]),
+    ) -> T
+    where
+        C: Copy,
+        T: Copy + Add<Output = T> + Mul<C, Output = T>,
+    {
+        let tap = |a: usize, b: usize, c: usize| {
+            let x = add_within::<X>(i, sx[a]);
+            let y = add_within::<Y>(j, sy[b]);
+            let z = add_within::<Z>(k, sz[c]);
+            let value = unsafe {
+                *input
+                    .inner
+                    .get_unchecked(x)
+                    .get_unchecked(y)
+                    .get_unchecked(z)
+            };
+            value * self.coeffs[a][b][c]
+        };
+        (1..KX * KY * KZ).fold(tap(0, 0, 0), |acc, n| {
+            acc + tap(n / (KY * KZ), n / KZ % KY, n % KZ)
+        })
+    }
+}
+
+macro_rules! impl_difference_operators {
+    ($($c:ty),*) => {$(
+        impl Stencil<$c, 3> {
+            /// The second-order central difference `(a[i + 1] - a[i - 1]) / 2`.
+            #[inline]
+            pub fn central_difference() -> Self {
+                Stencil::new(-1, [-0.5, 0.0, 0.5])
+            }
+
+            /// The second-order Laplacian `a[i - 1] - 2 a[i] + a[i + 1]`.
+            #[inline]
+            pub fn laplacian() -> Self {
+                Stencil::new(-1, [1.0, -2.0, 1.0])
+            }
+        }
+
+        impl Stencil<$c, 2> {
+            /// The first-order forward difference `a[i + 1] - a[i]`.
+            #[inline]
+            pub fn forward_difference() -> Self {
+                Stencil::new(0, [-1.0, 1.0])
+            }
+
+            /// The first-order backward difference `a[i] - a[i - 1]`.
+            #[inline]
+            pub fn backward_difference() -> Self {
+                Stencil::new(-1, [-1.0, 1.0])
+            }
+        }
+
+        impl Stencil2<$c, 3, 3> {
+            /// The five-point Laplacian.
+            #[inline]
+            pub fn laplacian() -> Self {
+                Stencil2::new(
+                    [-1, -1],
+                    [[0.0, 1.0, 0.0], [1.0, -4.0, 1.0], [0.0, 1.0, 0.0]],
+                )
+            }
+        }
+
+        impl Stencil3<$c, 3, 3, 3> {
+            /// The seven-point Laplacian.
+            #[inline]
+            pub fn laplacian() -> Self {
+                let mut coeffs = [[[0.0; 3]; 3]; 3];
+                coeffs[1][1] = [1.0, -6.0, 1.0];
+                coeffs[0][1][1] = 1.0;
+                coeffs[2][1][1] = 1.0;
+                coeffs[1][0][1] = 1.0;
+                coeffs[1][2][1] = 1.0;
+                Stencil3::new([-1, -1, -1], coeffs)
+            }
+        }
+    )*};
+}
+
+impl_difference_operators!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::{Stencil, Stencil2, Stencil3};
+    use crate::{p_arr, PeriodicArray, PeriodicGrid2, PeriodicGrid3};
+
+    #[test]
+    pub fn differences_wrap_at_the_boundary() {
+        let u = p_arr![1.0, 2.0, 4.0, 8.0];
+
+        assert_eq!(
+            *Stencil::<f64, 2>::forward_difference().apply(&u),
+            [1.0, 2.0, 4.0, -7.0]
+        );
+        assert_eq!(
+            *Stencil::<f64, 2>::backward_difference().apply(&u),
+            [-7.0, 1.0, 2.0, 4.0]
+        );
+        assert_eq!(
+            *Stencil::<f64, 3>::central_difference().apply(&u),
+            [-3.0, 1.5, 3.0, -1.5]
+        );
+
+        let mut out = PeriodicArray::new([0.0; 4]);
+        Stencil::<f64, 3>::laplacian().apply_into(&u, &mut out);
+        assert_eq!(*out, [8.0, 1.0, 2.0, -11.0]);
+    }
+
+    #[test]
+    pub fn custom_coefficients() {
+        let u = p_arr![1, 2, 3, 4, 5];
+        // a[i - 2] + 10 a[i + 2], with integer coefficients
+        let s = Stencil::new(-2, [1, 0, 0, 0, 10]);
+
+        let expected: [i32; 5] = core::array::from_fn(|i| u[i as isize - 2] + 10 * u[i + 2]);
+        assert_eq!(*s.apply(&u), expected);
+        // offsets larger than the period wrap as well
+        assert_eq!(
+            Stencil::new(3, [1]).apply(&u),
+            Stencil::new(-2, [1]).apply(&u)
+        );
+    }
+
+    #[test]
+    pub fn central_difference_of_sine() {
+        const N: usize = 64;
+        let h = 2.0 * core::f64::consts::PI / N as f64;
+        let u = PeriodicArray::<f64, N>::new(core::array::from_fn(|i| (i as f64 * h).sin()));
+
+        let du = Stencil::<f64, 3>::central_difference()
+            .scaled(1.0 / h)
+            .apply(&u);
+        for (i, d) in du.iter().enumerate() {
+            assert!((d - (i as f64 * h).cos()).abs() < 2e-3);
+        }
+    }
+
+    #[test]
+    pub fn laplacian_2d_and_3d() {
+        let mut g = PeriodicGrid2::new([[0.0; 3]; 4]);
+        g[[0, 0]] = 1.0;
+        let lap = Stencil2::<f64, 3, 3>::laplacian().apply(&g);
+        assert_eq!(
+            *lap,
+            [
+                [-4.0, 1.0, 1.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0]
+            ]
+        );
+
+        let mut g = PeriodicGrid3::new([[[0.0f32; 3]; 3]; 3]);
+        g[[0, 0, 0]] = 1.0;
+        let mut lap = PeriodicGrid3::new([[[0.0; 3]; 3]; 3]);
+        Stencil3::<f32, 3, 3, 3>::laplacian().apply_into(&g, &mut lap);
+        assert_eq!(lap[[0, 0, 0]], -6.0);
+        for n in [
+            [-1, 0, 0],
+            [1, 0, 0],
+            [0, -1, 0],
+            [0, 1, 0],
+            [0, 0, -1],
+            [0, 0, 1],
+        ] {
+            assert_eq!(lap[n], 1.0);
+        }
+        assert_eq!(lap.iter().flatten().flatten().sum::<f32>(), 0.0);
+    }
+}
@@ -0,0 +1,240 @@
+//! Arrays that change by a fixed transformation every period.
+//!
+//! A [`TwistedArray`] reads index `i + k * N` as the element at `i` transformed by a
+//! [`Twist`] for period `k`. This covers Bloch-periodic data, where every period multiplies
+//! by a phase factor, Möbius-like topologies, where every period flips the sign, and any
+//! other per-period rule given as a closure.
+//!
+//! # Examples
+//!
+//! ```
+//! use periodic_array::twist::{Negate, Phase, TwistedArray};
+//!
+//! let mobius = TwistedArray::new([1, 2, 3], Negate);
+//! assert_eq!(mobius.get(4), -2);
+//! assert_eq!(mobius.get(-1), -3);
+//!
+//! let growth = TwistedArray::new([1.0, 1.5], Phase(2.0));
+//! assert_eq!(growth.get(5), 6.0);
+//! assert_eq!(growth.get(-4), 0.25);
+//!
+//! // Every period is shifted up by 10.
+//! let shift = |value: i32, period: isize| value + 10 * period as i32;
+//! let staircase = TwistedArray::new([0, 1, 2], shift);
+//! assert_eq!(staircase.get(7), 21);
+//! ```
+
+use core::ops::{Deref, DerefMut, Div, Mul, Neg};
+
+use crate::PeriodicArray;
+
+/// A transformation applied to elements read `period` periods away from the stored ones.
+///
+/// Period `0` must leave values unchanged. Implemented for closures
+/// `Fn(T, isize) -> T` taking the stored value and the period.
+pub trait Twist<T> {
+    /// Returns `value` as seen `period` periods away.
+    fn apply(&self, value: T, period: isize) -> T;
+}
+
+impl<T, F: Fn(T, isize) -> T> Twist<T> for F {
+    #[inline(always)]
+    fn apply(&self, value: T, period: isize) -> T {
+        self(value, period)
+    }
+}
+
+/// Negates every other period, the same as [`AntiPeriodic`](crate::boundary::AntiPeriodic).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Negate;
+
+impl<T: Neg<Output = T>> Twist<T> for Negate {
+    #[inline(always)]
+    fn apply(&self, value: T, period: isize) -> T {
+        if period & 1 == 1 {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+/// Multiplies by the factor once per period, so index `i + k * N` reads `a[i] * factor^k`.
+///
+/// With a complex factor `e^(ikN)` this is Bloch periodicity for wave vector `k`. Negative
+/// periods divide by the factor. Powers are computed by repeated squaring, in O(log k)
+/// multiplications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Phase<F>(pub F);
+
+impl<T, F> Twist<T> for Phase<F>
+where
+    T: Mul<F, Output = T> + Div<F, Output = T>,
+    F: Copy + Mul<Output = F>,
+{
+    #[inline]
+    fn apply(&self, mut value: T, period: isize) -> T {
+        let mut base = self.0;
+        let mut exponent = period.unsigned_abs();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                value = if period < 0 {
+                    value / base
+                } else {
+                    value * base
+                };
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base * base;
+            }
+        }
+        value
+    }
+}
+
+/// A fixed-size array that repeats with the per-period transformation `W`.
+///
+/// The elements are stored and addressed as in a [`PeriodicArray`]; [`get`](TwistedArray::get)
+/// splits the index into an element and a period count with
+/// [`PeriodicArray::get_wrapped_with_period`] and returns the element transformed for that
+/// period.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements held in the array.
+/// * `N` - The compile-time fixed size of the array.
+/// * `W` - The per-period transformation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwistedArray<T, const N: usize, W> {
+    inner: PeriodicArray<T, N>,
+    twist: W,
+}
+
+impl<T, const N: usize, W> TwistedArray<T, N, W> {
+    /// Wraps `inner`, transforming it by `twist` in every period other than the stored one.
+    #[inline(always)]
+    pub fn new(inner: [T; N], twist: W) -> Self {
+        TwistedArray {
+            inner: PeriodicArray::new(inner),
+            twist,
+        }
+    }
+
+    /// Returns the value at `index`, the wrapped element transformed for its period.
+    #[inline]
+    pub fn get(&self, index: isize) -> T
+    where
+        T: Clone,
+        W: Twist<T>,
+    {
+        let (value, period) = self.inner.get_wrapped_with_period(index);
+        if period == 0 {
+            value.clone()
+        } else {
+            self.twist.apply(value.clone(), period)
+        }
+    }
+
+    /// Returns the per-period transformation.
+    #[inline(always)]
+    pub fn twist(&self) -> &W {
+        &self.twist
+    }
+
+    /// Returns the per-period transformation mutably, e.g. to change a [`Phase`] factor.
+    #[inline(always)]
+    pub fn twist_mut(&mut self) -> &mut W {
+        &mut self.twist
+    }
+
+    /// Returns the elements as a `PeriodicArray`, dropping the transformation.
+    #[inline(always)]
+    pub fn into_periodic(self) -> PeriodicArray<T, N> {
+        self.inner
+    }
+}
+
+impl<T, const N: usize, W> Deref for TwistedArray<T, N, W> {
+    type Target = [T; N];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.inner.inner
+    }
+}
+
+impl<T, const N: usize, W> DerefMut for TwistedArray<T, N, W> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Negate, Phase, TwistedArray};
+    use crate::boundary::{AntiPeriodic, BoundedArray};
+
+    #[test]
+    pub fn negate_matches_antiperiodic_boundary() {
+        let twisted = TwistedArray::new([1, 2, 3], Negate);
+        let bounded = BoundedArray::new([1, 2, 3], AntiPeriodic);
+
+        assert!((-10..10).all(|i| twisted.get(i) == bounded.get(i)));
+        assert_eq!(twisted.get(isize::MIN), bounded.get(isize::MIN));
+    }
+
+    #[test]
+    pub fn phase_powers() {
+        let mut pa = TwistedArray::new([3.0, 5.0], Phase(2.0));
+
+        assert_eq!(pa.get(1), 5.0);
+        assert_eq!(pa.get(2), 6.0);
+        assert_eq!(pa.get(21), 5.0 * 1024.0);
+        assert_eq!(pa.get(-1), 2.5);
+        assert_eq!(pa.get(-20), 3.0 / 1024.0);
+
+        pa.twist_mut().0 = -1.0;
+        pa[0] = 4.0;
+        assert_eq!(pa.get(-2), -4.0);
+        assert_eq!(*pa.into_periodic(), [4.0, 5.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "fft")]
+    pub fn bloch_phase() {
+        use crate::fft::Complex;
+
+        const N: usize = 4;
+        let k = 0.3;
+        let data: [_; N] = core::array::from_fn(|i| Complex::new(i as f64, 1.0));
+        let bloch = TwistedArray::new(data, Phase(Complex::from_polar(1.0, k * N as f64)));
+
+        for i in -9isize..9 {
+            let stored = data[i.rem_euclid(N as isize) as usize];
+            let period = i.div_euclid(N as isize) as f64;
+            let expected = stored * Complex::from_polar(1.0, k * N as f64 * period);
+            assert!((bloch.get(i) - expected).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    pub fn closure_twist() {
+        // odd periods reflect the values about 30
+        let data = [10, 20, 30];
+        let pa = TwistedArray::new(
+            data,
+            |value: i32, period: isize| {
+                if period % 2 == 0 {
+                    value
+                } else {
+                    60 - value
+                }
+            },
+        );
+        assert_eq!(
+            (0..6).map(|i| pa.get(i)).collect::<Vec<_>>(),
+            [10, 20, 30, 50, 40, 30]
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+
+use crate::{FastMod, PeriodicArray, PeriodicIndex, PeriodicSlice, ZeroLengthError};
+
+/// A heap-allocated array whose length is chosen at runtime, with periodic access to its elements.
+///
+/// Indexing follows the same wrapping rules as [`PeriodicArray`], with the period being the
+/// length of the vector. The length is fixed once constructed: a `PeriodicVec` derefs to a
+/// slice rather than a `Vec`, so it can never shrink to zero.
+///
+/// Since the period is not a compile-time constant, a [`FastMod`] is precomputed on
+/// construction so that indexing issues no hardware division, except for `u128` and `i128`
+/// indices too large for a `usize`.
+///
+/// # Zero Length
+///
+/// Wrapping by a period of zero is meaningless, so a `PeriodicVec` is never empty.
+/// [`PeriodicVec::new`] panics when given an empty `Vec`, while [`PeriodicVec::try_new`]
+/// returns a [`ZeroLengthError`].
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::PeriodicVec;
+///
+/// let pv = PeriodicVec::new(vec![1, 2, 3]);
+/// assert_eq!(pv[4], 2);
+/// assert_eq!(pv[-1], 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PeriodicVec<T> {
+    inner: Vec<T>,
+    modulus: FastMod,
+}
+
+impl<T> PeriodicVec<T> {
+    /// Wraps `inner`, using its length as the period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inner` is empty.
+    #[inline]
+    pub fn new(inner: Vec<T>) -> Self {
+        assert!(!inner.is_empty(), "PeriodicVec must not be empty");
+        let modulus = FastMod::new(inner.len());
+        PeriodicVec { inner, modulus }
+    }
+
+    /// Wraps `inner`, using its length as the period, or fails if `inner` is empty.
+    #[inline]
+    pub fn try_new(inner: Vec<T>) -> Result<Self, ZeroLengthError> {
+        if inner.is_empty() {
+            return Err(ZeroLengthError);
+        }
+        let modulus = FastMod::new(inner.len());
+        Ok(PeriodicVec { inner, modulus })
+    }
+
+    /// Borrows the elements as a [`PeriodicSlice`].
+    #[inline(always)]
+    pub fn as_periodic_slice(&self) -> PeriodicSlice<'_, T> {
+        PeriodicSlice {
+            inner: &self.inner,
+            modulus: self.modulus,
+        }
+    }
+
+    /// Returns the underlying `Vec`.
+    #[inline(always)]
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner
+    }
+}
+
+impl<T, I: PeriodicIndex> Index<I> for PeriodicVec<T> {
+    type Output = T;
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        unsafe { self.inner.get_unchecked(index.wrap_with(&self.modulus)) }
+    }
+}
+
+impl<T, I: PeriodicIndex> IndexMut<I> for PeriodicVec<T> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        unsafe { self.inner.get_unchecked_mut(index.wrap_with(&self.modulus)) }
+    }
+}
+
+impl<T> Deref for PeriodicVec<T> {
+    type Target = [T];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for PeriodicVec<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T, const N: usize> From<PeriodicArray<T, N>> for PeriodicVec<T> {
+    #[inline]
+    fn from(array: PeriodicArray<T, N>) -> Self {
+        PeriodicVec {
+            inner: Vec::from(array.inner),
+            modulus: const { FastMod::new(N) },
+        }
+    }
+}
+
+impl<T: Clone> From<PeriodicSlice<'_, T>> for PeriodicVec<T> {
+    #[inline]
+    fn from(slice: PeriodicSlice<'_, T>) -> Self {
+        PeriodicVec {
+            inner: slice.inner.to_vec(),
+            modulus: slice.modulus,
+        }
+    }
+}
+
+impl<T> FromIterator<T> for PeriodicVec<T> {
+    /// Collects the elements into a `PeriodicVec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator is empty. Collect into a `Vec` and use
+    /// [`PeriodicVec::try_new`] to handle that case.
+    #[inline]
+    fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+        PeriodicVec::new(iter.into_iter().collect())
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for PeriodicArray<T, N> {
+    type Error = Vec<T>;
+
+    /// Converts a `Vec` of length `N` into a `PeriodicArray`, handing the vector back
+    /// unchanged if its length differs.
+    #[inline]
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        <[T; N]>::try_from(vec).map(PeriodicArray::new)
+    }
+}
+
+impl<T, const N: usize> TryFrom<PeriodicVec<T>> for PeriodicArray<T, N> {
+    type Error = PeriodicVec<T>;
+
+    /// Converts a `PeriodicVec` of length `N` into a `PeriodicArray`, handing the
+    /// vector back unchanged if its length differs.
+    #[inline]
+    fn try_from(vec: PeriodicVec<T>) -> Result<Self, Self::Error> {
+        match <[T; N]>::try_from(vec.inner) {
+            Ok(inner) => Ok(PeriodicArray { inner }),
+            Err(inner) => Err(PeriodicVec {
+                inner,
+                modulus: vec.modulus,
+            }),
+        }
+    }
+}
+
+impl<'a, T> From<&'a PeriodicVec<T>> for PeriodicSlice<'a, T> {
+    #[inline(always)]
+    fn from(vec: &'a PeriodicVec<T>) -> Self {
+        vec.as_periodic_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{p_arr, PeriodicArray, PeriodicVec, ZeroLengthError};
+
+    #[test]
+    pub fn index_into_vec() {
+        let mut pv = PeriodicVec::new(vec![1, 2, 3]);
+
+        assert_eq!(pv[0], 1);
+        assert_eq!(pv[4], 2);
+        assert_eq!(pv[-1], 3);
+
+        pv[5u8] = 30;
+        assert_eq!(pv[-1], 30);
+    }
+
+    #[test]
+    pub fn convert_to_and_from_array() {
+        let pa = p_arr![1, 2, 3];
+
+        let pv = PeriodicVec::from(p_arr![1, 2, 3]);
+        assert_eq!(&*pv, &[1, 2, 3]);
+        assert_eq!(PeriodicArray::<_, 3>::try_from(pv.clone()), Ok(pa));
+        assert_eq!(PeriodicArray::<_, 2>::try_from(pv.clone()), Err(pv));
+    }
+
+    #[test]
+    pub fn collect_and_convert_from_vec() {
+        let pv: PeriodicVec<_> = (1..=4).map(|x| x * 10).collect();
+        assert_eq!(pv[-1], 40);
+
+        let pa: Result<PeriodicArray<_, 2>, _> = vec![1, 2].try_into();
+        assert_eq!(pa, Ok(p_arr![1, 2]));
+        assert_eq!(PeriodicArray::<_, 2>::try_from(vec![1]), Err(vec![1]));
+    }
+
+    #[test]
+    pub fn try_new_rejects_empty() {
+        assert_eq!(PeriodicVec::<u8>::try_new(Vec::new()), Err(ZeroLengthError));
+        assert_eq!(PeriodicVec::try_new(vec![1]).map(|pv| pv[7]), Ok(1));
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn empty_vec_panics() {
+        PeriodicVec::<u8>::new(Vec::new());
+    }
+}
@@ -0,0 +1,360 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Index;
+
+use crate::{PeriodicArray, PeriodicIndex};
+
+/// A borrowed run of `len` consecutive elements of a [`PeriodicArray`], starting anywhere in
+/// the period and continuing across the seam.
+///
+/// Indices into a window are relative to its start and bounds-checked against its length,
+/// which may exceed `N` (elements then repeat). Windows compare equal to each other and to
+/// slices element-wise, regardless of where the seam falls.
+///
+/// Created with [`PeriodicArray::window`].
+///
+/// # Examples
+///
+/// ```
+/// use periodic_array::p_arr;
+///
+/// let pa = p_arr![1, 2, 3, 4, 5];
+/// let w = pa.window(-2, 4);
+/// assert_eq!(w[0], 4);
+/// assert_eq!(w, [4, 5, 1, 2][..]);
+/// assert!(w.iter().eq(&[4, 5, 1, 2]));
+/// ```
+pub struct Window<'a, T, const N: usize> {
+    data: &'a [T; N],
+    start: usize,
+    len: usize,
+}
+
+impl<'a, T, const N: usize> Window<'a, T, N> {
+    /// Returns the number of elements in the window.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the window has no elements.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the position of the first element within the period, in `0..N`.
+    #[inline(always)]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the element at `index` relative to the start, or `None` past the end.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index < self.len {
+            Some(unsafe { self.get_unchecked(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the window's elements.
+    #[inline]
+    pub fn iter(&self) -> WindowIter<'a, T, N> {
+        WindowIter {
+            data: self.data,
+            start: self.start,
+            front: 0,
+            back: self.len,
+        }
+    }
+
+    /// Returns the window as at most two contiguous slices, split at the seam.
+    ///
+    /// The second slice is empty when the window does not cross the seam.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window is longer than `N`, as it cannot be expressed as two slices.
+    #[inline]
+    pub fn as_two_slices(&self) -> (&'a [T], &'a [T]) {
+        two_slices(self.data, self.start, self.len)
+    }
+
+    /// Element at logical offset `index`, without checking it against `len`.
+    #[inline(always)]
+    unsafe fn get_unchecked(&self, index: usize) -> &'a T {
+        self.data.get_unchecked(offset::<N>(self.start, index))
+    }
+}
+
+/// Returns the position `index` elements after `start`, where `start < N`, without overflowing.
+#[inline(always)]
+pub(crate) fn offset<const N: usize>(start: usize, index: usize) -> usize {
+    add_within::<N>(start, index % N)
+}
+
+/// Returns `(a + b) % N` for `a, b < N`, without overflowing.
+#[inline(always)]
+pub(crate) fn add_within<const N: usize>(a: usize, b: usize) -> usize {
+    let to_seam = N - a;
+    if b < to_seam {
+        a + b
+    } else {
+        b - to_seam
+    }
+}
+
+#[inline]
+fn two_slices<T>(data: &[T], start: usize, len: usize) -> (&[T], &[T]) {
+    assert!(len <= data.len(), "window longer than the period");
+    let (tail, head) = data.split_at(start);
+    if len <= head.len() {
+        (&head[..len], &[])
+    } else {
+        (head, &tail[..len - head.len()])
+    }
+}
+
+#[inline]
+fn two_slices_mut<T>(data: &mut [T], start: usize, len: usize) -> (&mut [T], &mut [T]) {
+    assert!(len <= data.len(), "window longer than the period");
+    let (tail, head) = data.split_at_mut(start);
+    if len <= head.len() {
+        (&mut head[..len], &mut [])
+    } else {
+        let rest = len - head.len();
+        (head, &mut tail[..rest])
+    }
+}
+
+impl<T, const N: usize> Clone for Window<'_, T, N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for Window<'_, T, N> {}
+
+impl<T, const N: usize> Index<usize> for Window<'_, T, N> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(
+            index < self.len,
+            "index {index} out of range for window of length {}",
+            self.len
+        );
+        unsafe { self.get_unchecked(index) }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Window<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<Window<'_, U, M>> for Window<'_, T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Window<'_, U, M>) -> bool {
+        self.len == other.len && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for Window<'_, T, N> {}
+
+impl<T, U, const N: usize> PartialEq<[U]> for Window<'_, T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.len == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for Window<'a, T, N> {
+    type Item = &'a T;
+    type IntoIter = WindowIter<'a, T, N>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &Window<'a, T, N> {
+    type Item = &'a T;
+    type IntoIter = WindowIter<'a, T, N>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`Window`].
+///
+/// Created with [`Window::iter`].
+#[derive(Debug)]
+pub struct WindowIter<'a, T, const N: usize> {
+    data: &'a [T; N],
+    start: usize,
+    /// Logical offset of the next element from the front.
+    front: usize,
+    /// Logical offset one past the next element from the back.
+    back: usize,
+}
+
+impl<T, const N: usize> Clone for WindowIter<'_, T, N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        WindowIter { ..*self }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for WindowIter<'a, T, N> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let item = unsafe { self.data.get_unchecked(offset::<N>(self.start, self.front)) };
+        self.front += 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front += n.min(self.back - self.front);
+        self.next()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for WindowIter<'_, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(unsafe { self.data.get_unchecked(offset::<N>(self.start, self.back)) })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for WindowIter<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for WindowIter<'_, T, N> {}
+
+impl<T, const N: usize> PeriodicArray<T, N> {
+    /// Returns a view of `len` consecutive elements beginning at the wrapped `start`.
+    ///
+    /// The view may cross the seam and may be longer than `N`.
+    #[inline]
+    pub fn window<I: PeriodicIndex>(&self, start: I, len: usize) -> Window<'_, T, N> {
+        Window {
+            data: &self.inner,
+            start: start.wrap(N),
+            len,
+        }
+    }
+
+    /// Returns `len` consecutive elements beginning at the wrapped `start` as two contiguous
+    /// slices, split at the seam like `VecDeque::as_slices`.
+    ///
+    /// The second slice is empty when the range does not cross the seam.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use periodic_array::p_arr;
+    ///
+    /// let pa = p_arr![1, 2, 3, 4, 5];
+    /// assert_eq!(pa.as_two_slices(3, 4), (&[4, 5][..], &[1, 2][..]));
+    /// assert_eq!(pa.as_two_slices(1, 2), (&[2, 3][..], &[][..]));
+    /// ```
+    #[inline]
+    pub fn as_two_slices<I: PeriodicIndex>(&self, start: I, len: usize) -> (&[T], &[T]) {
+        two_slices(&self.inner, start.wrap(N), len)
+    }
+
+    /// Mutable version of [`as_two_slices`](PeriodicArray::as_two_slices).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than `N`.
+    #[inline]
+    pub fn as_two_slices_mut<I: PeriodicIndex>(
+        &mut self,
+        start: I,
+        len: usize,
+    ) -> (&mut [T], &mut [T]) {
+        two_slices_mut(&mut self.inner, start.wrap(N), len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::p_arr;
+
+    #[test]
+    pub fn window_across_seam() {
+        let pa = p_arr![1, 2, 3, 4, 5];
+        let w = pa.window(3, 8);
+
+        assert_eq!(w.len(), 8);
+        assert_eq!(w[0], 4);
+        assert_eq!(w[2], 1);
+        assert_eq!(w[7], 1);
+        assert_eq!(w.get(8), None);
+        assert_eq!(w, [4, 5, 1, 2, 3, 4, 5, 1][..]);
+        assert!(w.iter().rev().eq(&[1, 5, 4, 3, 2, 1, 5, 4]));
+        assert_eq!(w.iter().len(), 8);
+    }
+
+    #[test]
+    pub fn windows_compare_across_seam() {
+        let a = p_arr![1, 2, 3, 4];
+        let b = p_arr![3, 4, 1, 2];
+
+        assert_eq!(a.window(0, 4), b.window(2, 4));
+        assert_eq!(a.window(-1, 2), b.window(1, 2));
+        assert_ne!(a.window(0, 4), b.window(0, 4));
+        assert_ne!(a.window(0, 3), a.window(0, 4));
+    }
+
+    #[test]
+    pub fn two_slices() {
+        let mut pa = p_arr![1, 2, 3, 4, 5];
+
+        assert_eq!(pa.as_two_slices(-2, 5), (&[4, 5][..], &[1, 2, 3][..]));
+        assert_eq!(pa.as_two_slices(0, 5), (&[1, 2, 3, 4, 5][..], &[][..]));
+        assert_eq!(pa.window(4, 3).as_two_slices(), (&[5][..], &[1, 2][..]));
+
+        let (head, tail) = pa.as_two_slices_mut(4, 2);
+        head[0] = 50;
+        tail[0] = 10;
+        assert_eq!(*pa, [10, 2, 3, 4, 50]);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn index_past_window_panics() {
+        let pa = p_arr![1, 2, 3];
+        let _ = pa.window(1, 2)[2];
+    }
+}